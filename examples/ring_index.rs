@@ -0,0 +1,53 @@
+//! Times ring buffers whose indexes wrap with a mask,
+//! because their capacity is a power of two,
+//! against ones of a similar capacity that wrap with a modulo:
+//!
+//! ```sh
+//! cargo run --release --example ring_index
+//! ```
+
+use flowerpot::{FlowerLog, FlowerWorkDeque};
+use std::hint::black_box;
+use std::time::Instant;
+
+const ROUNDS: u32 = 1_000_000;
+
+fn time(name: &str, mut f: impl FnMut()) {
+    let start = Instant::now();
+
+    for _ in 0..ROUNDS {
+        f();
+    }
+
+    println!("{name:>22}: {:?} per call", start.elapsed() / ROUNDS);
+}
+
+fn deque<const N: usize>(name: &str) {
+    let mut deque = FlowerWorkDeque::<u64, N>::new();
+    let (mut worker, stealer) = deque.split();
+    let mut next = 0;
+
+    time(name, || {
+        worker.push(black_box(next)).unwrap();
+        next += 1;
+        black_box(stealer.steal());
+    });
+}
+
+fn log<const BYTES: usize>(name: &str) {
+    let mut log = FlowerLog::<BYTES>::new();
+    let mut timestamp = 0;
+
+    time(name, || {
+        log.push_with_timestamp(timestamp, black_box(b"flowerpot"))
+            .unwrap();
+        timestamp += 1;
+    });
+}
+
+fn main() {
+    deque::<1024>("deque, mask (1024)");
+    deque::<1000>("deque, modulo (1000)");
+    log::<4096>("log, mask (4096)");
+    log::<4000>("log, modulo (4000)");
+}
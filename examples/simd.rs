@@ -0,0 +1,48 @@
+//! Times the numeric pot operations,
+//! run it with and without the `simd` feature to compare the implementations:
+//!
+//! ```sh
+//! cargo run --release --example simd
+//! cargo run --release --example simd --features simd
+//! ```
+
+use flowerpot::FlowerPot;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ROUNDS: u32 = 100_000;
+
+fn time(name: &str, mut f: impl FnMut()) {
+    let start = Instant::now();
+
+    for _ in 0..ROUNDS {
+        f();
+    }
+
+    println!("{name:>10}: {:?} per call", start.elapsed() / ROUNDS);
+}
+
+fn main() {
+    let mut bytes = FlowerPot::<u8, 4096>::new();
+    bytes.push_iter((0..4096).map(|index| index as u8));
+
+    let mut samples = FlowerPot::<f32, 1024>::new();
+    samples.push_iter((0..1024).map(|index| index as f32));
+
+    let expected: Vec<f32> = samples.get_init_slice().to_vec();
+
+    println!("simd feature enabled: {}", cfg!(feature = "simd"));
+
+    time("contains", || {
+        black_box(black_box(&bytes).contains(black_box(0xff)));
+    });
+    time("sum", || {
+        black_box(black_box(&samples).sum());
+    });
+    time("position", || {
+        black_box(black_box(&samples).position(black_box(1023.0)));
+    });
+    time("eq_slice", || {
+        black_box(black_box(&samples).eq_slice(black_box(&expected)));
+    });
+}
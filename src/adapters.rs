@@ -0,0 +1,118 @@
+use crate::FlowerPot;
+
+/// Iterator adapters collecting into inline storage.
+pub trait IteratorExt: Iterator + Sized {
+    /// Groups the items into `FlowerPot`s of `N` items,
+    /// the last one holds the remaining items if there are fewer than `N`.
+    /// fails to compile if `N` is 0.
+    fn chunks_pot<const N: usize>(self) -> ChunksPot<Self, N> {
+        const { assert!(N > 0, "chunks must hold at least 1 item") };
+
+        ChunksPot { iter: self }
+    }
+
+    /// Wraps the iterator so up to `K` upcoming items can be peeked at.
+    fn lookahead<const K: usize>(self) -> Lookahead<Self, K> {
+        Lookahead {
+            iter: self,
+            buf: FlowerPot::new(),
+        }
+    }
+}
+
+impl<I: Iterator> IteratorExt for I {}
+
+/// Iterator returned by `IteratorExt::chunks_pot`.
+pub struct ChunksPot<I, const N: usize> {
+    iter: I,
+}
+
+impl<I: Iterator, const N: usize> Iterator for ChunksPot<I, N> {
+    type Item = FlowerPot<I::Item, N>;
+
+    fn next(&mut self) -> Option<FlowerPot<I::Item, N>> {
+        let mut chunk = FlowerPot::new();
+
+        if chunk.push_iter(&mut self.iter) == 0 {
+            return None;
+        }
+
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+
+        (lower.div_ceil(N), upper.map(|upper| upper.div_ceil(N)))
+    }
+}
+
+/// Iterator returned by `IteratorExt::lookahead`,
+/// buffering up to `K` upcoming items so any of them can be peeked at.
+pub struct Lookahead<I: Iterator, const K: usize> {
+    iter: I,
+    /// Items taken from `iter` ahead of time, next one first.
+    buf: FlowerPot<I::Item, K>,
+}
+
+impl<I: Iterator, const K: usize> Lookahead<I, K> {
+    /// Obtains an immutable reference to the next item without advancing.
+    /// returns `None` if the iterator is exhausted or `K` is 0.
+    pub fn peek(&mut self) -> Option<&I::Item> {
+        self.peek_nth(0)
+    }
+
+    /// Obtains an immutable reference to the item `n` places ahead without advancing,
+    /// `peek_nth(0)` being the next one.
+    /// returns `None` if the iterator ends before it or `n` is not below `K`.
+    pub fn peek_nth(&mut self, n: usize) -> Option<&I::Item> {
+        if n >= K {
+            return None;
+        }
+
+        if self.buf.len() <= n {
+            let missing = n + 1 - self.buf.len();
+            self.buf.push_iter(self.iter.by_ref().take(missing));
+        }
+
+        self.buf.get(n)
+    }
+
+    /// Returns the next item if `accept` returns `true` for it,
+    /// otherwise it stays the next item.
+    pub fn next_if<F: FnOnce(&I::Item) -> bool>(&mut self, accept: F) -> Option<I::Item> {
+        if accept(self.peek()?) {
+            return self.next();
+        }
+
+        None
+    }
+
+    /// Returns the amount of items buffered ahead of time.
+    #[inline]
+    pub fn buffered(&self) -> usize {
+        self.buf.len()
+    }
+}
+
+impl<I: Iterator, const K: usize> Iterator for Lookahead<I, K> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        if self.buf.is_empty() {
+            return self.iter.next();
+        }
+
+        self.buf.remove(0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        let buffered = self.buf.len();
+
+        (
+            lower.saturating_add(buffered),
+            upper.and_then(|upper| upper.checked_add(buffered)),
+        )
+    }
+}
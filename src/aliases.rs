@@ -0,0 +1,16 @@
+use crate::FlowerPot;
+
+/// `FlowerPot` holding up to 4 items.
+pub type SmallPot4<T> = FlowerPot<T, 4>;
+
+/// `FlowerPot` holding up to 8 items.
+pub type SmallPot8<T> = FlowerPot<T, 8>;
+
+/// `FlowerPot` holding up to 16 items.
+pub type SmallPot16<T> = FlowerPot<T, 16>;
+
+/// `FlowerPot` holding up to 64 items, such as a line of text or samples.
+pub type Line<T> = FlowerPot<T, 64>;
+
+/// `FlowerPot` holding a 4 KiB page of bytes.
+pub type Page = FlowerPot<u8, 4096>;
@@ -0,0 +1,98 @@
+use std::alloc::{GlobalAlloc, Layout};
+use std::cell::UnsafeCell;
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Bump allocator handing out memory from `BYTES` bytes of inline storage,
+/// so a `static` of it can serve as the whole heap through `#[global_allocator]`.
+/// freeing only gives memory back if it was the latest allocation,
+/// everything else is reclaimed by `reset`.
+pub struct FlowerPotAllocator<const BYTES: usize> {
+    buf: UnsafeCell<[u8; BYTES]>,
+    /// Offset of the first free byte.
+    next: AtomicUsize,
+}
+
+// SAFETY: Every allocation is a distinct region of `buf`,
+// claimed by a single successful compare-exchange on `next`.
+unsafe impl<const BYTES: usize> Sync for FlowerPotAllocator<BYTES> {}
+
+impl<const BYTES: usize> FlowerPotAllocator<BYTES> {
+    /// Creates a new `FlowerPotAllocator` with every byte free.
+    pub const fn new() -> FlowerPotAllocator<BYTES> {
+        Self {
+            buf: UnsafeCell::new([0; BYTES]),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the amount of bytes handed out, including alignment padding.
+    pub fn used(&self) -> usize {
+        self.next.load(Ordering::Relaxed)
+    }
+
+    /// Returns the amount of bytes that were never handed out.
+    pub fn remaining(&self) -> usize {
+        BYTES - self.used()
+    }
+
+    /// Frees every allocation at once.
+    ///
+    /// # Safety
+    /// no memory allocated before may be used afterwards.
+    #[cfg(not(feature = "forbid-unsafe-api"))]
+    pub unsafe fn reset(&self) {
+        self.next.store(0, Ordering::Release);
+    }
+
+    fn base(&self) -> *mut u8 {
+        self.buf.get().cast()
+    }
+}
+
+impl<const BYTES: usize> Default for FlowerPotAllocator<BYTES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+unsafe impl<const BYTES: usize> GlobalAlloc for FlowerPotAllocator<BYTES> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let base = self.base();
+        let mut next = self.next.load(Ordering::Relaxed);
+
+        loop {
+            let padding = base.wrapping_add(next).align_offset(layout.align());
+
+            let Some(end) = next
+                .checked_add(padding)
+                .and_then(|start| start.checked_add(layout.size()))
+                .filter(|end| *end <= BYTES)
+            else {
+                return ptr::null_mut();
+            };
+
+            match self
+                .next
+                .compare_exchange_weak(next, end, Ordering::AcqRel, Ordering::Relaxed)
+            {
+                // SAFETY: `next + padding` lies within `buf`, as `end` does.
+                Ok(_) => return unsafe { base.add(next + padding) },
+                Err(current) => next = current,
+            }
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: `ptr` was handed out by `alloc`, so it lies within `buf`.
+        let end = unsafe { ptr.offset_from(self.base()) } as usize + layout.size();
+
+        // Only the latest allocation can be given back, the rest stays until `reset`.
+        let _ = self.next.compare_exchange(
+            end,
+            end - layout.size(),
+            Ordering::AcqRel,
+            Ordering::Relaxed,
+        );
+    }
+}
@@ -0,0 +1,56 @@
+use crate::FlowerPot;
+
+/// Compile-time checks of a capacity `N` against a bound `M`.
+/// referencing one of the associated constants fails to compile
+/// if the check does not hold for the given `N` and `M`.
+pub struct CapacityAssert<const N: usize, const M: usize>;
+
+impl<const N: usize, const M: usize> CapacityAssert<N, M> {
+    /// Holds if `N` is bigger than or equal to `M`.
+    pub const AT_LEAST: () = assert!(N >= M, "capacity is smaller than required");
+
+    /// Holds if `N` is smaller than or equal to `M`.
+    pub const AT_MOST: () = assert!(N <= M, "capacity is bigger than allowed");
+
+    /// Holds if `N` is equal to `M`.
+    pub const EXACTLY: () = assert!(N == M, "capacity is not the required one");
+
+    /// Holds if `N` is a power of two, in which case ring indexes
+    /// wrap around with a mask instead of a division. `M` is ignored.
+    pub const POWER_OF_TWO: () = assert!(N.is_power_of_two(), "capacity is not a power of two");
+}
+
+/// Wraps `index` around a ring of `N` slots,
+/// using a mask instead of a division when `N` is a power of two.
+#[inline(always)]
+pub(crate) const fn wrap<const N: usize>(index: usize) -> usize {
+    if N.is_power_of_two() {
+        index & (N - 1)
+    } else {
+        index % N
+    }
+}
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Total amount of items the `FlowerPot` can hold.
+    pub const CAPACITY: usize = N;
+
+    /// Fails to compile if `N` is smaller than `M`,
+    /// so wrappers can demand a minimum capacity from their callers.
+    #[inline]
+    pub const fn assert_capacity_at_least<const M: usize>() {
+        CapacityAssert::<N, M>::AT_LEAST
+    }
+
+    /// Fails to compile if `N` is bigger than `M`.
+    #[inline]
+    pub const fn assert_capacity_at_most<const M: usize>() {
+        CapacityAssert::<N, M>::AT_MOST
+    }
+
+    /// Fails to compile if `N` is not a power of two.
+    #[inline]
+    pub const fn assert_capacity_power_of_two() {
+        CapacityAssert::<N, 0>::POWER_OF_TWO
+    }
+}
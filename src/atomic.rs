@@ -0,0 +1,123 @@
+use crate::{FlowerPot, storage_full};
+use std::cell::UnsafeCell;
+use std::io::Result;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Append-only `FlowerPot` that any amount of threads can push to.
+/// pushes reserve a slot atomically and publish it once written,
+/// readers see the prefix of items that were published so far.
+pub struct AtomicFlowerPot<T, const N: usize> {
+    items: [UnsafeCell<MaybeUninit<T>>; N],
+    /// Amount of slots handed out to pushes.
+    reserved: AtomicUsize,
+    /// Amount of slots that were written and published, in order.
+    committed: AtomicUsize,
+}
+
+// SAFETY: Every slot is written once by the push that reserved it,
+// and only shared after being published through `committed`.
+unsafe impl<T: Send + Sync, const N: usize> Sync for AtomicFlowerPot<T, N> {}
+
+impl<T, const N: usize> AtomicFlowerPot<T, N> {
+    /// Creates a new empty `AtomicFlowerPot`.
+    pub fn new() -> AtomicFlowerPot<T, N> {
+        let items = [const { UnsafeCell::new(MaybeUninit::uninit()) }; N];
+
+        Self {
+            items,
+            reserved: AtomicUsize::new(0),
+            committed: AtomicUsize::new(0),
+        }
+    }
+
+    /// Pushes an item to the pot.
+    /// waits for concurrent pushes to earlier slots to be published first.
+    /// returns `Err` with `StorageFull` if every slot is taken.
+    pub fn push(&self, item: T) -> Result<()> {
+        let reserve =
+            self.reserved
+                .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |reserved| {
+                    (reserved < N).then_some(reserved + 1)
+                });
+
+        let Ok(index) = reserve else {
+            let err = storage_full();
+
+            return Err(err);
+        };
+
+        // SAFETY: the slot was reserved by this push only,
+        // and is not visible to readers until it is published.
+        unsafe { (*self.items[index].get()).write(item) };
+
+        // Publish in order, so the committed items always form a prefix.
+        while self
+            .committed
+            .compare_exchange_weak(index, index + 1, Ordering::Release, Ordering::Relaxed)
+            .is_err()
+        {
+            std::hint::spin_loop();
+        }
+
+        Ok(())
+    }
+
+    /// Returns the amount of published items.
+    pub fn len(&self) -> usize {
+        self.committed.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if no items were published yet.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if every slot is taken.
+    pub fn is_full(&self) -> bool {
+        self.reserved.load(Ordering::Relaxed) >= N
+    }
+
+    /// Obtains an immutable reference to the published items.
+    pub fn as_slice(&self) -> &[T] {
+        let len = self.len();
+
+        // SAFETY: the first `len` slots were written and published,
+        // and are never written again.
+        unsafe { std::slice::from_raw_parts(self.items.as_ptr().cast::<T>(), len) }
+    }
+
+    /// Moves the items into a regular `FlowerPot`.
+    pub fn into_pot(mut self) -> FlowerPot<T, N> {
+        let len = *self.committed.get_mut();
+        let mut pot = FlowerPot::new();
+
+        for slot in &mut self.items[..len] {
+            // SAFETY: the slot was published and is moved out only once,
+            // `committed` is reset below so it is not dropped again.
+            let item = unsafe { slot.get_mut().assume_init_read() };
+            let _ = pot.push(item);
+        }
+
+        *self.committed.get_mut() = 0;
+
+        pot
+    }
+}
+
+impl<T, const N: usize> Default for AtomicFlowerPot<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for AtomicFlowerPot<T, N> {
+    fn drop(&mut self) {
+        let len = *self.committed.get_mut();
+
+        for slot in &mut self.items[..len] {
+            // SAFETY: the slot was published and not moved out of.
+            unsafe { slot.get_mut().assume_init_drop() };
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{FlowerPot, invalid_input, storage_full, unexpected_eof};
+use std::io::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Order in which the bits of a field are packed into bytes.
+pub enum BitOrder {
+    /// The most significant bit of a field comes first,
+    /// bytes are filled starting from their highest bit.
+    MsbFirst,
+    /// The least significant bit of a field comes first,
+    /// bytes are filled starting from their lowest bit.
+    LsbFirst,
+}
+
+/// Writes bit fields into a `FlowerPot` of bytes.
+/// writing always starts on a fresh byte after the existing ones.
+pub struct BitWriter<'a, const N: usize> {
+    pot: &'a mut FlowerPot<u8, N>,
+    order: BitOrder,
+    /// Amount of bits used in the last byte, `0` if it is complete.
+    used: u32,
+}
+
+impl<'a, const N: usize> BitWriter<'a, N> {
+    /// Creates a new `BitWriter` appending to `pot`.
+    pub fn new(pot: &'a mut FlowerPot<u8, N>, order: BitOrder) -> BitWriter<'a, N> {
+        Self {
+            pot,
+            order,
+            used: 0,
+        }
+    }
+
+    /// Writes the lowest `count` bits of `value`.
+    /// returns `Err` with `InvalidInput` if `count` is bigger than 64
+    /// and `StorageFull` if the bits do not fit, in which case nothing is written.
+    pub fn write_bits(&mut self, value: u64, count: u32) -> Result<()> {
+        if count > u64::BITS {
+            let err = invalid_input();
+
+            return Err(err);
+        }
+
+        let free = if self.used == 0 { 0 } else { 8 - self.used };
+        let needed = count.saturating_sub(free).div_ceil(8) as usize;
+
+        if needed > self.pot.remaining_capacity() {
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        for index in 0..count {
+            let shift = match self.order {
+                BitOrder::MsbFirst => count - 1 - index,
+                BitOrder::LsbFirst => index,
+            };
+
+            self.put_bit((value >> shift) & 1 == 1);
+        }
+
+        Ok(())
+    }
+
+    /// Writes a single bit.
+    /// returns `Err` if the bit does not fit.
+    pub fn write_bit(&mut self, bit: bool) -> Result<()> {
+        self.write_bits(bit as u64, 1)
+    }
+
+    /// Returns `true` if the next bit starts a fresh byte.
+    #[inline]
+    pub fn is_aligned(&self) -> bool {
+        self.used == 0
+    }
+
+    /// Pads the last byte with zero bits,
+    /// so the next bit starts a fresh byte.
+    pub fn align(&mut self) {
+        self.used = 0;
+    }
+
+    fn put_bit(&mut self, bit: bool) {
+        if self.used == 0 {
+            // Capacity was checked by `write_bits`.
+            let _ = self.pot.push(0);
+        }
+
+        let last = self.pot.len() - 1;
+        let shift = match self.order {
+            BitOrder::MsbFirst => 7 - self.used,
+            BitOrder::LsbFirst => self.used,
+        };
+
+        self.pot[last] |= (bit as u8) << shift;
+        self.used = (self.used + 1) % 8;
+    }
+}
+
+/// Reads bit fields out of a `FlowerPot` of bytes.
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    order: BitOrder,
+    /// Index of the next bit to read.
+    pos: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Creates a new `BitReader` over the initialized bytes of `pot`.
+    pub fn new<const N: usize>(pot: &'a FlowerPot<u8, N>, order: BitOrder) -> BitReader<'a> {
+        Self {
+            bytes: pot.get_init_slice(),
+            order,
+            pos: 0,
+        }
+    }
+
+    /// Reads a field of `count` bits.
+    /// returns `Err` with `InvalidInput` if `count` is bigger than 64
+    /// and `UnexpectedEof` if not enough bits are left, in which case nothing is read.
+    pub fn read_bits(&mut self, count: u32) -> Result<u64> {
+        if count > u64::BITS {
+            let err = invalid_input();
+
+            return Err(err);
+        }
+
+        if count as usize > self.remaining() {
+            let err = unexpected_eof();
+
+            return Err(err);
+        }
+
+        let mut value = 0;
+
+        for index in 0..count {
+            let bit = self.take_bit() as u64;
+
+            match self.order {
+                BitOrder::MsbFirst => value = (value << 1) | bit,
+                BitOrder::LsbFirst => value |= bit << index,
+            }
+        }
+
+        Ok(value)
+    }
+
+    /// Reads a single bit.
+    /// returns `Err` if no bits are left.
+    pub fn read_bit(&mut self) -> Result<bool> {
+        self.read_bits(1).map(|bit| bit == 1)
+    }
+
+    /// Returns the amount of bits that are left to read.
+    #[inline]
+    pub fn remaining(&self) -> usize {
+        self.bytes.len() * 8 - self.pos
+    }
+
+    /// Skips the rest of the current byte,
+    /// so the next bit read starts a fresh byte.
+    pub fn align(&mut self) {
+        self.pos = self.pos.next_multiple_of(8);
+    }
+
+    fn take_bit(&mut self) -> bool {
+        let byte = self.bytes[self.pos / 8];
+        let used = (self.pos % 8) as u32;
+        let shift = match self.order {
+            BitOrder::MsbFirst => 7 - used,
+            BitOrder::LsbFirst => used,
+        };
+
+        self.pos += 1;
+
+        (byte >> shift) & 1 == 1
+    }
+}
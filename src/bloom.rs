@@ -0,0 +1,132 @@
+use crate::invalid_input;
+use std::hash::{Hash, Hasher};
+use std::io::Result;
+
+/// Bloom filter stored in `BYTES` bytes of inline storage,
+/// giving it `BYTES * 8` bits.
+/// `maybe_contains` never misses inserted items,
+/// but can report items that were never inserted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlowerBloom<const BYTES: usize> {
+    bits: [u8; BYTES],
+    hashes: u32,
+}
+
+impl<const BYTES: usize> FlowerBloom<BYTES> {
+    /// Creates a new empty `FlowerBloom`
+    /// setting `hashes` bits for every item, at least one.
+    pub const fn new(hashes: u32) -> FlowerBloom<BYTES> {
+        let hashes = if hashes == 0 { 1 } else { hashes };
+
+        Self {
+            bits: [0; BYTES],
+            hashes,
+        }
+    }
+
+    /// Returns the amount of bits set for every item.
+    #[inline]
+    pub const fn hashes(&self) -> u32 {
+        self.hashes
+    }
+
+    /// Returns the amount of bits in the filter.
+    #[inline]
+    pub const fn bit_count(&self) -> usize {
+        BYTES * 8
+    }
+
+    /// Returns the amount of bits that are set.
+    pub fn count_ones(&self) -> usize {
+        self.bits
+            .iter()
+            .map(|byte| byte.count_ones() as usize)
+            .sum()
+    }
+
+    /// Adds an item to the filter.
+    /// does nothing if the filter has no bits.
+    pub fn insert<T: Hash + ?Sized>(&mut self, item: &T) {
+        for bit in self.bit_indexes(item) {
+            self.bits[bit / 8] |= 1 << (bit % 8);
+        }
+    }
+
+    /// Returns `true` if the item may have been inserted,
+    /// `false` means that it was definitely never inserted.
+    pub fn maybe_contains<T: Hash + ?Sized>(&self, item: &T) -> bool {
+        self.bit_indexes(item)
+            .all(|bit| self.bits[bit / 8] & (1 << (bit % 8)) != 0)
+    }
+
+    /// Merges every item of `other` into the filter.
+    /// returns `Err` with `InvalidInput` if both filters use a different amount of hashes.
+    pub fn union(&mut self, other: &FlowerBloom<BYTES>) -> Result<()> {
+        if self.hashes != other.hashes {
+            let err = invalid_input();
+
+            return Err(err);
+        }
+
+        for (byte, other) in self.bits.iter_mut().zip(other.bits) {
+            *byte |= other;
+        }
+
+        Ok(())
+    }
+
+    /// Removes every item from the filter.
+    pub fn clear(&mut self) {
+        self.bits = [0; BYTES];
+    }
+
+    fn bit_indexes<T: Hash + ?Sized>(
+        &self,
+        item: &T,
+    ) -> impl Iterator<Item = usize> + use<T, BYTES> {
+        let mut hasher = Fnv1a::new();
+        item.hash(&mut hasher);
+
+        let hash = hasher.finish();
+        let first = hash;
+        let second = hash.rotate_left(32) | 1;
+        let bits = (BYTES * 8) as u64;
+        let count = if bits == 0 { 0 } else { self.hashes as u64 };
+
+        // Double hashing derives every index from the same two hashes.
+        (0..count)
+            .map(move |index| (first.wrapping_add(index.wrapping_mul(second)) % bits) as usize)
+    }
+}
+
+/// FNV-1a with a final mixing step,
+/// which keeps bloom filter contents stable across builds.
+struct Fnv1a {
+    state: u64,
+}
+
+impl Fnv1a {
+    fn new() -> Fnv1a {
+        Self {
+            state: 0xCBF2_9CE4_8422_2325,
+        }
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.state ^= byte as u64;
+            self.state = self.state.wrapping_mul(0x0100_0000_01B3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        let mut hash = self.state;
+        hash ^= hash >> 33;
+        hash = hash.wrapping_mul(0xFF51_AFD7_ED55_8CCD);
+        hash ^= hash >> 33;
+
+        hash
+    }
+}
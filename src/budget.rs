@@ -0,0 +1,19 @@
+use std::mem::size_of;
+
+/// Returns how many items of `T` fit in a budget of `bytes`,
+/// fails to compile when evaluated in a constant for a zero-sized `T`.
+pub const fn capacity_for_bytes<T>(bytes: usize) -> usize {
+    assert!(size_of::<T>() != 0, "zero-sized items have no byte budget");
+
+    bytes / size_of::<T>()
+}
+
+/// Names the `FlowerPot` type holding as many items of the given type
+/// as fit in a budget of the given amount of bytes,
+/// such as `pot_for_bytes!(u32, 1024)` for a `FlowerPot<u32, 256>`.
+#[macro_export]
+macro_rules! pot_for_bytes {
+    ($ty:ty, $bytes:expr) => {
+        $crate::FlowerPot<$ty, { $crate::capacity_for_bytes::<$ty>($bytes) }>
+    };
+}
@@ -0,0 +1,254 @@
+use crate::{FlowerPot, PotCursor, invalid_input, storage_full};
+use std::io::{self, Result, Write};
+
+impl<const N: usize> FlowerPot<u8, N> {
+    /// Creates a `PotCursor` positioned at the start of the `FlowerPot`,
+    /// through which it can be read, written and seeked as a stream.
+    pub fn io_cursor(&mut self) -> PotCursor<'_, N> {
+        PotCursor::new(self)
+    }
+
+    /// Writes the initialized bytes of the `FlowerPot` into `writer`.
+    /// stops early if the writer accepts no more bytes or would block,
+    /// in which case the remaining bytes are moved to the front.
+    /// returns the amount of bytes that were flushed.
+    pub fn flush_to<W: Write>(&mut self, mut writer: W) -> Result<usize> {
+        let mut flushed = 0;
+
+        let result = loop {
+            if flushed == self.pos {
+                break Ok(());
+            }
+
+            match writer.write(&self.get_init_slice()[flushed..]) {
+                Ok(0) => break Ok(()),
+                Ok(written) => flushed += written,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+                Err(err) if err.kind() == io::ErrorKind::WouldBlock => break Ok(()),
+                Err(err) => break Err(err),
+            }
+        };
+
+        self.consume(flushed);
+
+        result.map(|_| flushed)
+    }
+
+    /// Pushes every byte of `bytes`.
+    /// returns `Err` with `StorageFull` if they do not fit, in which case nothing is pushed.
+    pub fn put_slice(&mut self, bytes: &[u8]) -> Result<()> {
+        if bytes.len() > self.remaining_capacity() {
+            self.record_rejected();
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        self.push_iter(bytes.iter().copied());
+
+        Ok(())
+    }
+
+    /// Pushes `value` encoded as an unsigned LEB128 varint.
+    /// returns the amount of bytes pushed,
+    /// or `Err` if they do not fit, in which case nothing is pushed.
+    pub fn put_varint(&mut self, mut value: u64) -> Result<usize> {
+        let mut buf = [0; 10];
+        let mut len = 0;
+
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+
+            if value == 0 {
+                buf[len] = byte;
+                len += 1;
+                break;
+            }
+
+            buf[len] = byte | 0x80;
+            len += 1;
+        }
+
+        self.put_slice(&buf[..len])?;
+
+        Ok(len)
+    }
+
+    /// Reads an unsigned LEB128 varint at `index`.
+    /// returns the value and the amount of bytes it took,
+    /// or `None` if it is truncated or does not fit in a `u64`.
+    pub fn get_varint(&self, index: usize) -> Option<(u64, usize)> {
+        let bytes = self.get_init_slice().get(index..)?;
+        let mut value = 0_u64;
+
+        for (len, &byte) in bytes.iter().enumerate().take(10) {
+            let bits = (byte & 0x7F) as u64;
+            let shift = 7 * len as u32;
+
+            if shift == 63 && bits > 1 {
+                return None;
+            }
+
+            value |= bits << shift;
+
+            if byte & 0x80 == 0 {
+                return Some((value, len + 1));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the index of the first occurrence of `byte`.
+    /// scans a whole word at a time, returns `None` if it is not found.
+    pub fn position(&self, byte: u8) -> Option<usize> {
+        memchr(byte, self.get_init_slice())
+    }
+
+    /// Returns the index of the first occurrence of `needle`.
+    /// an empty needle is found at index `0`,
+    /// returns `None` if it is not found.
+    pub fn find(&self, needle: &[u8]) -> Option<usize> {
+        let haystack = self.get_init_slice();
+
+        let Some((&first, rest)) = needle.split_first() else {
+            return Some(0);
+        };
+
+        let mut start = 0;
+
+        while haystack.len() - start >= needle.len() {
+            let index = start + memchr(first, &haystack[start..=haystack.len() - needle.len()])?;
+
+            if haystack[index + 1..].starts_with(rest) {
+                return Some(index);
+            }
+
+            start = index + 1;
+        }
+
+        None
+    }
+
+    /// Returns `true` if the initialized bytes start with `prefix`.
+    pub fn starts_with(&self, prefix: &[u8]) -> bool {
+        self.get_init_slice().starts_with(prefix)
+    }
+
+    /// Returns `true` if the initialized bytes end with `suffix`.
+    pub fn ends_with(&self, suffix: &[u8]) -> bool {
+        self.get_init_slice().ends_with(suffix)
+    }
+
+    /// Pushes `K` zeroed bytes to be filled in later through `fill_slot`,
+    /// such as a length prefix that is only known after the body is written.
+    /// returns `Err` if they do not fit, in which case nothing is pushed.
+    pub fn reserve_slot<const K: usize>(&mut self) -> Result<SlotHandle<K>> {
+        let index = self.pos;
+        self.put_slice(&[0; K])?;
+
+        Ok(SlotHandle { index })
+    }
+
+    /// Overwrites the bytes reserved by `reserve_slot` with `bytes`.
+    /// returns `Err` with `InvalidInput` if the `FlowerPot`
+    /// was truncated past the slot since it was reserved.
+    pub fn fill_slot<const K: usize>(
+        &mut self,
+        handle: SlotHandle<K>,
+        bytes: [u8; K],
+    ) -> Result<()> {
+        let end = handle.index + K;
+
+        match self.get_init_slice_mut().get_mut(handle.index..end) {
+            Some(slot) => {
+                slot.copy_from_slice(&bytes);
+
+                Ok(())
+            }
+            None => {
+                let err = invalid_input();
+
+                Err(err)
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+/// Region of `K` bytes reserved by `FlowerPot::reserve_slot`.
+pub struct SlotHandle<const K: usize> {
+    index: usize,
+}
+
+impl<const K: usize> SlotHandle<K> {
+    /// Returns the index of the first reserved byte.
+    #[inline]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+macro_rules! int_accessors {
+    ($($put:ident, $get:ident, $int:ty, $to:ident, $from:ident;)*) => {
+        impl<const N: usize> FlowerPot<u8, N> {
+            $(
+                #[doc = concat!("Pushes a `", stringify!($int), "` using `", stringify!($to), "`.")]
+                /// returns `Err` if it does not fit, in which case nothing is pushed.
+                pub fn $put(&mut self, value: $int) -> Result<()> {
+                    self.put_slice(&value.$to())
+                }
+
+                #[doc = concat!("Reads a `", stringify!($int), "` at `index` using `", stringify!($from), "`.")]
+                /// returns `None` if not enough bytes are initialized.
+                pub fn $get(&self, index: usize) -> Option<$int> {
+                    let bytes = self.get_init_slice().get(index..)?.first_chunk()?;
+
+                    Some(<$int>::$from(*bytes))
+                }
+            )*
+        }
+    };
+}
+
+int_accessors! {
+    put_u16_le, get_u16_le, u16, to_le_bytes, from_le_bytes;
+    put_u16_be, get_u16_be, u16, to_be_bytes, from_be_bytes;
+    put_u32_le, get_u32_le, u32, to_le_bytes, from_le_bytes;
+    put_u32_be, get_u32_be, u32, to_be_bytes, from_be_bytes;
+    put_u64_le, get_u64_le, u64, to_le_bytes, from_le_bytes;
+    put_u64_be, get_u64_be, u64, to_be_bytes, from_be_bytes;
+}
+
+const WORD: usize = size_of::<usize>();
+const LOW_BITS: usize = usize::MAX / 0xFF;
+const HIGH_BITS: usize = LOW_BITS << 7;
+
+/// Finds the first occurrence of `needle` in `haystack`,
+/// comparing a whole `usize` worth of bytes per step.
+pub(crate) fn memchr(needle: u8, haystack: &[u8]) -> Option<usize> {
+    let repeated = LOW_BITS * needle as usize;
+    let mut chunks = haystack.chunks_exact(WORD);
+    let mut offset = 0;
+
+    for chunk in chunks.by_ref() {
+        let Some(&chunk) = chunk.first_chunk() else {
+            break;
+        };
+
+        let word = usize::from_ne_bytes(chunk) ^ repeated;
+
+        // A byte of `word` is zero exactly where the chunk matched `needle`.
+        if word.wrapping_sub(LOW_BITS) & !word & HIGH_BITS != 0 {
+            break;
+        }
+
+        offset += WORD;
+    }
+
+    haystack[offset..]
+        .iter()
+        .position(|&byte| byte == needle)
+        .map(|index| offset + index)
+}
@@ -0,0 +1,123 @@
+use crate::FlowerPot;
+use std::collections::VecDeque;
+
+/// Unbounded sequence of items stored in a chain of heap-allocated pots,
+/// growing one `FlowerPot` of `N` items at a time.
+/// whole pots can be appended and taken out again without copying their items.
+/// `PooledPotChain` takes its pots from a `PotPool` instead, for builds without `alloc`.
+pub struct PotChain<T, const N: usize> {
+    segments: VecDeque<Box<FlowerPot<T, N>>>,
+    len: usize,
+}
+
+impl<T, const N: usize> PotChain<T, N> {
+    /// Creates a new empty `PotChain`.
+    /// fails to compile if `N` is 0.
+    pub const fn new() -> PotChain<T, N> {
+        const { assert!(N > 0, "`PotChain` segments must hold at least 1 item") };
+
+        Self {
+            segments: VecDeque::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the amount of items in every segment combined.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the chain holds no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the amount of segments.
+    #[inline]
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Pushes an item to the last segment,
+    /// allocating a new one if it is full.
+    pub fn push(&mut self, item: T) {
+        let segment = match self.segments.back_mut() {
+            Some(segment) if !segment.is_full() => segment,
+            _ => {
+                self.segments.push_back(Box::new(FlowerPot::new()));
+                self.segments.back_mut().unwrap()
+            }
+        };
+
+        // Can not fail, the segment is not full.
+        let _ = segment.push(item);
+        self.len += 1;
+    }
+
+    /// Pops the last item.
+    /// returns `None` if the chain is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let segment = self.segments.back_mut()?;
+        let item = segment.pop();
+
+        if segment.is_empty() {
+            self.segments.pop_back();
+        }
+
+        self.len -= item.is_some() as usize;
+
+        item
+    }
+
+    /// Appends `pot` as a new segment without copying its items.
+    /// does nothing if it is empty.
+    pub fn append_pot(&mut self, pot: FlowerPot<T, N>) {
+        if pot.is_empty() {
+            return;
+        }
+
+        self.len += pot.len();
+        self.segments.push_back(Box::new(pot));
+    }
+
+    /// Takes the first segment out of the chain.
+    /// returns `None` if the chain is empty.
+    pub fn pop_segment(&mut self) -> Option<FlowerPot<T, N>> {
+        let segment = self.segments.pop_front()?;
+        self.len -= segment.len();
+
+        Some(*segment)
+    }
+
+    /// Iterates over the items of every segment in order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.segments().flatten()
+    }
+
+    /// Iterates over the segments in order.
+    pub fn segments(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.segments.iter().map(|segment| segment.get_init_slice())
+    }
+
+    /// Drops every item and segment.
+    pub fn clear(&mut self) {
+        self.segments.clear();
+        self.len = 0;
+    }
+}
+
+impl<T, const N: usize> Default for PotChain<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Extend<T> for PotChain<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
@@ -0,0 +1,174 @@
+use crate::FlowerPot;
+use std::io::Result;
+use std::ops::Deref;
+
+/// Checksum that can be computed incrementally.
+pub trait Checksum {
+    /// Type of the final checksum value.
+    type Output;
+
+    /// Feeds `bytes` into the checksum.
+    fn update(&mut self, bytes: &[u8]);
+
+    /// Returns the checksum of every byte fed so far.
+    fn digest(&self) -> Self::Output;
+
+    /// Resets the checksum to its initial state.
+    fn reset(&mut self);
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut index = 0;
+
+    while index < 256 {
+        let mut crc = index as u32;
+        let mut bit = 0;
+
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+
+            bit += 1;
+        }
+
+        table[index] = crc;
+        index += 1;
+    }
+
+    table
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// CRC-32 as used by Ethernet, zlib and PNG.
+pub struct Crc32 {
+    state: u32,
+}
+
+impl Crc32 {
+    /// Creates a new `Crc32` with no bytes fed.
+    pub const fn new() -> Crc32 {
+        Self { state: u32::MAX }
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Checksum for Crc32 {
+    type Output = u32;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            let index = (self.state as u8 ^ byte) as usize;
+            self.state = (self.state >> 8) ^ CRC32_TABLE[index];
+        }
+    }
+
+    fn digest(&self) -> u32 {
+        !self.state
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Fletcher-16 checksum.
+pub struct Fletcher16 {
+    low: u16,
+    high: u16,
+}
+
+impl Fletcher16 {
+    /// Creates a new `Fletcher16` with no bytes fed.
+    pub const fn new() -> Fletcher16 {
+        Self { low: 0, high: 0 }
+    }
+}
+
+impl Checksum for Fletcher16 {
+    type Output = u16;
+
+    fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.low = (self.low + byte as u16) % 255;
+            self.high = (self.high + self.low) % 255;
+        }
+    }
+
+    fn digest(&self) -> u16 {
+        (self.high << 8) | self.low
+    }
+
+    fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// `FlowerPot` of bytes that keeps a checksum
+/// of every byte pushed into it.
+#[derive(Debug, Default)]
+pub struct ChecksumPot<C: Checksum, const N: usize> {
+    pot: FlowerPot<u8, N>,
+    checksum: C,
+}
+
+impl<C: Checksum, const N: usize> ChecksumPot<C, N> {
+    /// Creates a new empty `ChecksumPot` using `checksum`.
+    pub fn new(checksum: C) -> ChecksumPot<C, N> {
+        Self {
+            pot: FlowerPot::new(),
+            checksum,
+        }
+    }
+
+    /// Pushes a byte and feeds it into the checksum.
+    /// returns `Err` if the pot is full.
+    pub fn push(&mut self, byte: u8) -> Result<()> {
+        self.pot.push(byte)?;
+        self.checksum.update(&[byte]);
+
+        Ok(())
+    }
+
+    /// Pushes every byte of `bytes` and feeds them into the checksum.
+    /// returns `Err` if they do not fit, in which case nothing is pushed.
+    pub fn put_slice(&mut self, bytes: &[u8]) -> Result<()> {
+        self.pot.put_slice(bytes)?;
+        self.checksum.update(bytes);
+
+        Ok(())
+    }
+
+    /// Returns the checksum of every byte pushed so far.
+    pub fn digest(&self) -> C::Output {
+        self.checksum.digest()
+    }
+
+    /// Drops every byte and resets the checksum.
+    pub fn clear(&mut self) {
+        self.pot.clear();
+        self.checksum.reset();
+    }
+
+    /// Returns the underlying `FlowerPot` and checksum.
+    pub fn into_inner(self) -> (FlowerPot<u8, N>, C) {
+        (self.pot, self.checksum)
+    }
+}
+
+impl<C: Checksum, const N: usize> Deref for ChecksumPot<C, N> {
+    type Target = FlowerPot<u8, N>;
+
+    fn deref(&self) -> &FlowerPot<u8, N> {
+        &self.pot
+    }
+}
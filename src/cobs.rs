@@ -0,0 +1,129 @@
+//! Consistent Overhead Byte Stuffing.
+//!
+//! Encoded frames contain no zero bytes,
+//! so a single zero byte can be used to delimit them.
+
+use crate::{FlowerPot, invalid_data};
+use std::io::Result;
+
+/// Encodes `src` and appends it to `dst`, without the zero delimiter.
+/// returns the amount of bytes appended,
+/// or `Err` with `StorageFull` if they do not fit, in which case nothing is appended.
+pub fn encode<const N: usize>(src: &[u8], dst: &mut FlowerPot<u8, N>) -> Result<usize> {
+    let start = dst.len();
+
+    encode_inner(src, dst).inspect_err(|_| dst.truncate(start))?;
+
+    Ok(dst.len() - start)
+}
+
+fn encode_inner<const N: usize>(src: &[u8], dst: &mut FlowerPot<u8, N>) -> Result<()> {
+    let mut code_index = dst.len();
+    let mut code = 1_u8;
+    dst.push(0)?;
+
+    for (index, &byte) in src.iter().enumerate() {
+        if byte != 0 {
+            dst.push(byte)?;
+            code += 1;
+        }
+
+        let last = index + 1 == src.len();
+
+        if byte == 0 || (code == 0xFF && !last) {
+            dst[code_index] = code;
+            code_index = dst.len();
+            code = 1;
+            dst.push(0)?;
+        }
+    }
+
+    dst[code_index] = code;
+
+    Ok(())
+}
+
+/// Decodes the frame `src` and appends it to `dst`,
+/// `src` may end with a single zero delimiter.
+/// returns the amount of bytes appended, or `Err` with `InvalidData` if the frame is malformed
+/// and `StorageFull` if the bytes do not fit, in both cases nothing is appended.
+pub fn decode<const N: usize>(src: &[u8], dst: &mut FlowerPot<u8, N>) -> Result<usize> {
+    let start = dst.len();
+    let src = src.strip_suffix(&[0]).unwrap_or(src);
+
+    decode_inner(src, dst).inspect_err(|_| dst.truncate(start))?;
+
+    Ok(dst.len() - start)
+}
+
+fn decode_inner<const N: usize>(src: &[u8], dst: &mut FlowerPot<u8, N>) -> Result<()> {
+    let mut index = 0;
+
+    while let Some((block, next)) = next_block(src, index)? {
+        dst.put_slice(block)?;
+
+        if src[index] != 0xFF && next < src.len() {
+            dst.push(0)?;
+        }
+
+        index = next;
+    }
+
+    Ok(())
+}
+
+/// Decodes the frame held by `pot` in place,
+/// the frame may end with a single zero delimiter.
+/// returns `Err` with `InvalidData` if the frame is malformed, in which case `pot` is untouched.
+pub fn decode_in_place<const N: usize>(pot: &mut FlowerPot<u8, N>) -> Result<()> {
+    let mut len = pot.len();
+
+    if pot.ends_with(&[0]) {
+        len -= 1;
+    }
+
+    // Validate first, so a malformed frame leaves the pot untouched.
+    let mut index = 0;
+
+    while let Some((_, next)) = next_block(&pot[..len], index)? {
+        index = next;
+    }
+
+    let bytes = &mut pot[..len];
+    let mut read = 0;
+    let mut write = 0;
+
+    while read < len {
+        let code = bytes[read] as usize;
+
+        // Decoding never writes past the position it reads from.
+        bytes.copy_within(read + 1..read + code, write);
+        write += code - 1;
+        read += code;
+
+        if code != 0xFF && read < len {
+            bytes[write] = 0;
+            write += 1;
+        }
+    }
+
+    pot.truncate(write);
+
+    Ok(())
+}
+
+/// Splits off the block starting at `index`,
+/// returns the data bytes of the block and the index of the next one.
+fn next_block(src: &[u8], index: usize) -> Result<Option<(&[u8], usize)>> {
+    let Some(&code) = src.get(index) else {
+        return Ok(None);
+    };
+
+    let next = index + code as usize;
+    let block = src.get(index + 1..next);
+
+    match block {
+        Some(block) if code != 0 && !block.contains(&0) => Ok(Some((block, next))),
+        _ => Err(invalid_data()),
+    }
+}
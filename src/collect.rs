@@ -0,0 +1,85 @@
+use crate::{FlowerPot, storage_full};
+use std::error::Error;
+use std::fmt;
+use std::io::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Error of operations that produce items which may fail,
+/// either because the items do not fit or because producing one failed.
+/// returned by `FlowerPot::collect_ok`, `FlowerPot::try_clone_from_slice`
+/// and `FlowerPot::try_push_with`.
+pub enum CollectError<E> {
+    /// The items did not fit.
+    StorageFull,
+    /// Producing an item failed.
+    Item(E),
+}
+
+impl<E: fmt::Display> fmt::Display for CollectError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CollectError::StorageFull => f.write_str("`FlowerPot` capacity exceeded"),
+            CollectError::Item(err) => err.fmt(f),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for CollectError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CollectError::StorageFull => None,
+            CollectError::Item(err) => Some(err),
+        }
+    }
+}
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Creates a new `FlowerPot` from the `Ok` items of `iter`,
+    /// stopping at the first `Err` item.
+    /// returns `Err` with `CollectError::Item` holding that error
+    /// and `CollectError::StorageFull` if there are more than `N` items.
+    pub fn collect_ok<E, I>(iter: I) -> std::result::Result<FlowerPot<T, N>, CollectError<E>>
+    where
+        I: IntoIterator<Item = std::result::Result<T, E>>,
+    {
+        let mut pot = Self::new();
+
+        for item in iter {
+            let item = item.map_err(CollectError::Item)?;
+
+            if pot.push(item).is_err() {
+                return Err(CollectError::StorageFull);
+            }
+        }
+
+        Ok(pot)
+    }
+
+    /// Creates a new `FlowerPot` from the `Ok` items of `iter`
+    /// and a second one from its `Err` items.
+    /// returns `Err` with `StorageFull` if either of them does not fit.
+    pub fn collect_either<E, I, const M: usize>(
+        iter: I,
+    ) -> Result<(FlowerPot<T, N>, FlowerPot<E, M>)>
+    where
+        I: IntoIterator<Item = std::result::Result<T, E>>,
+    {
+        let mut oks = Self::new();
+        let mut errs = FlowerPot::new();
+
+        for item in iter {
+            let pushed = match item {
+                Ok(item) => oks.push(item),
+                Err(err) => errs.push(err),
+            };
+
+            if pushed.is_err() {
+                let err = storage_full();
+
+                return Err(err);
+            }
+        }
+
+        Ok((oks, errs))
+    }
+}
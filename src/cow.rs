@@ -0,0 +1,82 @@
+use crate::FlowerPot;
+use std::io::Result;
+use std::ops::Deref;
+
+/// Either a borrowed slice or an owned `FlowerPot`,
+/// the slice is copied into inline storage on the first write.
+#[derive(Debug)]
+pub enum PotOrSlice<'a, T, const N: usize> {
+    /// Borrowed slice of items.
+    Borrowed(&'a [T]),
+    /// Owned `FlowerPot` of items.
+    Owned(FlowerPot<T, N>),
+}
+
+impl<'a, T, const N: usize> PotOrSlice<'a, T, N> {
+    /// Returns `true` if the items are borrowed.
+    #[inline]
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self, PotOrSlice::Borrowed(_))
+    }
+
+    /// Returns `true` if the items are owned.
+    #[inline]
+    pub fn is_owned(&self) -> bool {
+        matches!(self, PotOrSlice::Owned(_))
+    }
+
+    /// Obtains an immutable reference to the items.
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            PotOrSlice::Borrowed(slice) => slice,
+            PotOrSlice::Owned(pot) => pot.get_init_slice(),
+        }
+    }
+}
+
+impl<'a, T: Clone, const N: usize> PotOrSlice<'a, T, N> {
+    /// Obtains a mutable reference to the owned `FlowerPot`,
+    /// copying the borrowed items into it first if needed.
+    /// returns `Err` with `StorageFull` if the borrowed slice is longer than `N`.
+    pub fn to_mut(&mut self) -> Result<&mut FlowerPot<T, N>> {
+        if let PotOrSlice::Borrowed(slice) = *self {
+            let pot = FlowerPot::try_from_iter(slice.iter().cloned())?;
+            *self = PotOrSlice::Owned(pot);
+        }
+
+        match self {
+            PotOrSlice::Owned(pot) => Ok(pot),
+            PotOrSlice::Borrowed(_) => unreachable!(),
+        }
+    }
+
+    /// Converts into an owned `FlowerPot`,
+    /// copying the borrowed items if needed.
+    /// returns `Err` with `StorageFull` if the borrowed slice is longer than `N`.
+    pub fn into_owned(self) -> Result<FlowerPot<T, N>> {
+        match self {
+            PotOrSlice::Borrowed(slice) => FlowerPot::try_from_iter(slice.iter().cloned()),
+            PotOrSlice::Owned(pot) => Ok(pot),
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for PotOrSlice<'_, T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<'a, T, const N: usize> From<&'a [T]> for PotOrSlice<'a, T, N> {
+    fn from(slice: &'a [T]) -> PotOrSlice<'a, T, N> {
+        PotOrSlice::Borrowed(slice)
+    }
+}
+
+impl<T, const N: usize> From<FlowerPot<T, N>> for PotOrSlice<'_, T, N> {
+    fn from(pot: FlowerPot<T, N>) -> Self {
+        PotOrSlice::Owned(pot)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::FlowerPot;
+use std::io::Result;
+
+/// Cursor over a `FlowerPot` that can move around
+/// and insert or remove items at its position.
+/// the position ranges from `0` up to and including `len()`,
+/// where the last position points past the final item.
+pub struct CursorMut<'a, T, const N: usize> {
+    pot: &'a mut FlowerPot<T, N>,
+    index: usize,
+}
+
+impl<'a, T, const N: usize> CursorMut<'a, T, N> {
+    pub(crate) fn new(pot: &'a mut FlowerPot<T, N>) -> CursorMut<'a, T, N> {
+        Self { pot, index: 0 }
+    }
+
+    /// Returns the current position of the cursor.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Moves the cursor one item to the right.
+    /// returns `false` if the cursor is already past the final item.
+    pub fn move_next(&mut self) -> bool {
+        if self.index >= self.pot.len() {
+            return false;
+        }
+
+        self.index += 1;
+
+        true
+    }
+
+    /// Moves the cursor one item to the left.
+    /// returns `false` if the cursor is already at the first item.
+    pub fn move_prev(&mut self) -> bool {
+        if self.index == 0 {
+            return false;
+        }
+
+        self.index -= 1;
+
+        true
+    }
+
+    /// Moves the cursor to an specified position.
+    /// returns `false` if the position is past `len()`.
+    pub fn seek(&mut self, index: usize) -> bool {
+        if index > self.pot.len() {
+            return false;
+        }
+
+        self.index = index;
+
+        true
+    }
+
+    /// Obtains an immutable reference to the item at the cursor.
+    /// returns `None` if the cursor is past the final item.
+    pub fn current(&self) -> Option<&T> {
+        self.pot.get(self.index)
+    }
+
+    /// Obtains a mutable reference to the item at the cursor.
+    /// returns `None` if the cursor is past the final item.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.pot.get_mut(self.index)
+    }
+
+    /// Obtains an immutable reference to the item before the cursor.
+    /// returns `None` if the cursor is at the first item.
+    pub fn peek_prev(&self) -> Option<&T> {
+        self.pot.get(self.index.checked_sub(1)?)
+    }
+
+    /// Obtains an immutable reference to the item after the cursor.
+    /// returns `None` if there is no such item.
+    pub fn peek_next(&self) -> Option<&T> {
+        self.pot.get(self.index + 1)
+    }
+
+    /// Inserts an item before the cursor,
+    /// the cursor keeps pointing at the same item.
+    /// returns `Err` if the `FlowerPot` is full.
+    pub fn insert(&mut self, item: T) -> Result<()> {
+        self.pot.insert(self.index, item)?;
+        self.index += 1;
+
+        Ok(())
+    }
+
+    /// Removes the item at the cursor and returns it,
+    /// the cursor then points at the item that followed it.
+    /// returns `None` if the cursor is past the final item.
+    pub fn remove_current(&mut self) -> Option<T> {
+        self.pot.remove(self.index)
+    }
+
+    /// Obtains an immutable reference to the underlying `FlowerPot`.
+    pub fn as_pot(&self) -> &FlowerPot<T, N> {
+        self.pot
+    }
+}
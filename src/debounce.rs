@@ -0,0 +1,96 @@
+use crate::CapacityAssert;
+use crate::assert::wrap;
+
+/// Filter over the last `N` samples of a noisy input, such as a GPIO pin.
+/// the reported state only changes once `K` samples in a row agree on a new one,
+/// while the whole window of `N` samples is available for a majority vote.
+/// `K` defaults to `N` and must be between 1 and `N`.
+pub struct Debouncer<T, const N: usize, const K: usize = N> {
+    samples: [T; N],
+    /// Index the next sample is stored at.
+    head: usize,
+    /// Amount of equal samples in a row, up to `K`.
+    run: usize,
+    state: T,
+}
+
+impl<T: Copy + PartialEq, const N: usize, const K: usize> Debouncer<T, N, K> {
+    /// Creates a new `Debouncer` reporting `initial`,
+    /// with a window as if `N` samples of `initial` were taken.
+    /// fails to compile unless `K` is between 1 and `N`.
+    pub fn new(initial: T) -> Debouncer<T, N, K> {
+        let () = CapacityAssert::<K, 1>::AT_LEAST;
+        let () = CapacityAssert::<K, N>::AT_MOST;
+
+        Self {
+            samples: [initial; N],
+            head: 0,
+            run: K,
+            state: initial,
+        }
+    }
+
+    /// Records `sample`, replacing the oldest one in the window.
+    /// returns the new state if `sample` completed `K` equal samples in a row
+    /// that differ from the current state.
+    pub fn update(&mut self, sample: T) -> Option<T> {
+        let last = self.samples[wrap::<N>(self.head + N - 1)];
+
+        self.run = if sample == last {
+            (self.run + 1).min(K)
+        } else {
+            1
+        };
+        self.samples[self.head] = sample;
+        self.head = wrap::<N>(self.head + 1);
+
+        if self.run < K || sample == self.state {
+            return None;
+        }
+
+        self.state = sample;
+
+        Some(sample)
+    }
+
+    /// Returns the debounced state.
+    #[inline]
+    pub fn state(&self) -> T {
+        self.state
+    }
+
+    /// Returns the sample held by more than half of the window.
+    /// returns `None` if there is no such sample.
+    pub fn majority(&self) -> Option<T> {
+        // Boyer-Moore vote, the candidate is only a majority if it is confirmed.
+        let mut candidate = self.samples[0];
+        let mut count = 0;
+
+        for &sample in &self.samples {
+            if count == 0 {
+                candidate = sample;
+            }
+
+            count = if sample == candidate {
+                count + 1
+            } else {
+                count - 1
+            };
+        }
+
+        let votes = self
+            .samples
+            .iter()
+            .filter(|sample| **sample == candidate)
+            .count();
+
+        (votes * 2 > N).then_some(candidate)
+    }
+
+    /// Iterates over the samples in the window, oldest first.
+    pub fn samples(&self) -> impl DoubleEndedIterator<Item = &T> {
+        let (newer, older) = self.samples.split_at(self.head);
+
+        older.iter().chain(newer)
+    }
+}
@@ -0,0 +1,113 @@
+use crate::FlowerPot;
+use std::io::Result;
+use std::ops::Deref;
+
+/// Two `FlowerPot`s used in a ping-pong fashion,
+/// the back pot is filled while the front pot is drained.
+/// `swap` exchanges the roles of both pots.
+#[derive(Debug, Default)]
+pub struct DoubleBuffer<T, const N: usize> {
+    pots: [FlowerPot<T, N>; 2],
+    front: usize,
+}
+
+impl<T, const N: usize> DoubleBuffer<T, N> {
+    /// Creates a new `DoubleBuffer` with both pots empty.
+    pub fn new() -> DoubleBuffer<T, N> {
+        Self {
+            pots: [FlowerPot::new(), FlowerPot::new()],
+            front: 0,
+        }
+    }
+
+    /// Exchanges the front and back pots.
+    /// the contents of both pots are left untouched.
+    pub fn swap(&mut self) {
+        self.front ^= 1;
+    }
+
+    /// Obtains a handle to the front pot, which is being drained.
+    pub fn front(&mut self) -> Front<'_, T, N> {
+        Front {
+            pot: &mut self.pots[self.front],
+        }
+    }
+
+    /// Obtains a handle to the back pot, which is being filled.
+    pub fn back(&mut self) -> Back<'_, T, N> {
+        Back {
+            pot: &mut self.pots[self.front ^ 1],
+        }
+    }
+
+    /// Obtains handles to both pots at once.
+    pub fn split(&mut self) -> (Front<'_, T, N>, Back<'_, T, N>) {
+        let [first, second] = &mut self.pots;
+
+        let (front, back) = if self.front == 0 {
+            (first, second)
+        } else {
+            (second, first)
+        };
+
+        (Front { pot: front }, Back { pot: back })
+    }
+}
+
+/// Draining side of a `DoubleBuffer`.
+pub struct Front<'a, T, const N: usize> {
+    pot: &'a mut FlowerPot<T, N>,
+}
+
+impl<T, const N: usize> Front<'_, T, N> {
+    /// Pops an item from the front pot.
+    /// returns `None` if the pot is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.pot.pop()
+    }
+
+    /// Obtains a mutable reference to the initialized part of the front pot.
+    pub fn get_init_slice_mut(&mut self) -> &mut [T] {
+        self.pot.get_init_slice_mut()
+    }
+
+    /// Drops every item in the front pot.
+    pub fn clear(&mut self) {
+        self.pot.clear()
+    }
+}
+
+impl<T, const N: usize> Deref for Front<'_, T, N> {
+    type Target = FlowerPot<T, N>;
+
+    fn deref(&self) -> &FlowerPot<T, N> {
+        self.pot
+    }
+}
+
+/// Filling side of a `DoubleBuffer`.
+pub struct Back<'a, T, const N: usize> {
+    pot: &'a mut FlowerPot<T, N>,
+}
+
+impl<T, const N: usize> Back<'_, T, N> {
+    /// Pushes an item to the back pot.
+    /// returns `Err` if the pot is full.
+    pub fn push(&mut self, item: T) -> Result<()> {
+        self.pot.push(item)
+    }
+
+    /// Pushes items from `iter` until the back pot is full.
+    /// returns the amount of items that were pushed.
+    pub fn push_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        self.pot.push_iter(iter)
+    }
+}
+
+impl<T, const N: usize> Deref for Back<'_, T, N> {
+    type Target = FlowerPot<T, N>;
+
+    fn deref(&self) -> &FlowerPot<T, N> {
+        self.pot
+    }
+}
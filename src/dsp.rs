@@ -0,0 +1,204 @@
+//! Helpers for buffers of audio or sensor samples.
+//!
+//! Fixed-point samples use the Q15 format,
+//! a signed 16-bit value with 15 fractional bits.
+
+use crate::{FlowerPot, invalid_input, storage_full};
+use std::io::Result;
+
+/// Numeric sample that can be averaged,
+/// sums are accumulated in a wider type so they do not overflow.
+pub trait Sample: Copy + PartialOrd {
+    /// Type sums of samples are accumulated in.
+    type Acc: Copy;
+
+    /// Sum of no samples.
+    const ZERO: Self::Acc;
+
+    /// Adds the sample to `acc`.
+    fn accumulate(self, acc: Self::Acc) -> Self::Acc;
+
+    /// Removes the sample from `acc` again.
+    fn remove(self, acc: Self::Acc) -> Self::Acc;
+
+    /// Divides `acc` by `count`, converting it back to a sample.
+    fn mean(acc: Self::Acc, count: usize) -> Self;
+}
+
+macro_rules! int_samples {
+    ($($int:ty),*) => {
+        $(
+            impl Sample for $int {
+                type Acc = i64;
+
+                const ZERO: i64 = 0;
+
+                fn accumulate(self, acc: i64) -> i64 {
+                    acc + self as i64
+                }
+
+                fn remove(self, acc: i64) -> i64 {
+                    acc - self as i64
+                }
+
+                fn mean(acc: i64, count: usize) -> $int {
+                    (acc / count as i64) as $int
+                }
+            }
+        )*
+    };
+}
+
+int_samples!(i8, i16, i32, u8, u16, u32);
+
+macro_rules! float_samples {
+    ($($float:ty),*) => {
+        $(
+            impl Sample for $float {
+                type Acc = f64;
+
+                const ZERO: f64 = 0.0;
+
+                fn accumulate(self, acc: f64) -> f64 {
+                    acc + self as f64
+                }
+
+                fn remove(self, acc: f64) -> f64 {
+                    acc - self as f64
+                }
+
+                fn mean(acc: f64, count: usize) -> $float {
+                    (acc / count as f64) as $float
+                }
+            }
+        )*
+    };
+}
+
+float_samples!(f32, f64);
+
+/// Appends the average of every window of `window` consecutive samples of `src` to `dst`,
+/// which are `src.len() - window + 1` averages, or none if `src` is shorter than `window`.
+/// returns `Err` with `InvalidInput` if `window` is 0
+/// and `StorageFull` if the averages do not fit, in which case nothing is appended.
+pub fn windowed_average<T: Sample, const N: usize>(
+    src: &[T],
+    window: usize,
+    dst: &mut FlowerPot<T, N>,
+) -> Result<()> {
+    if window == 0 {
+        let err = invalid_input();
+
+        return Err(err);
+    }
+
+    let count = (src.len() + 1).saturating_sub(window);
+
+    if count > dst.remaining_capacity() {
+        let err = storage_full();
+
+        return Err(err);
+    }
+
+    if count == 0 {
+        return Ok(());
+    }
+
+    let mut acc = src[..window]
+        .iter()
+        .fold(T::ZERO, |acc, &sample| sample.accumulate(acc));
+    dst.push(T::mean(acc, window))?;
+
+    for (&old, &new) in src.iter().zip(&src[window..]) {
+        acc = new.accumulate(old.remove(acc));
+        dst.push(T::mean(acc, window))?;
+    }
+
+    Ok(())
+}
+
+/// Multiplies every sample of `pot` by `factor`.
+pub fn scale<const N: usize>(pot: &mut FlowerPot<f32, N>, factor: f32) {
+    for sample in pot.get_init_slice_mut() {
+        *sample *= factor;
+    }
+}
+
+/// Multiplies every Q15 sample of `pot` by the Q15 `factor`,
+/// rounding to nearest and saturating at the numeric bounds.
+pub fn scale_q15<const N: usize>(pot: &mut FlowerPot<i16, N>, factor: i16) {
+    for sample in pot.get_init_slice_mut() {
+        let product = (*sample as i32 * factor as i32 + (1 << 14)) >> 15;
+
+        *sample = product.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+    }
+}
+
+/// Limits every sample of `pot` to the range between `min` and `max`.
+pub fn clamp<T: Sample, const N: usize>(pot: &mut FlowerPot<T, N>, min: T, max: T) {
+    for sample in pot.get_init_slice_mut() {
+        if *sample < min {
+            *sample = min;
+        } else if *sample > max {
+            *sample = max;
+        }
+    }
+}
+
+/// Appends the samples of `left` and `right` to `dst`, alternating between them.
+/// returns `Err` with `InvalidInput` if they differ in length
+/// and `StorageFull` if they do not fit, in which case nothing is appended.
+pub fn interleave<T: Copy, const N: usize>(
+    left: &[T],
+    right: &[T],
+    dst: &mut FlowerPot<T, N>,
+) -> Result<()> {
+    if left.len() != right.len() {
+        let err = invalid_input();
+
+        return Err(err);
+    }
+
+    if left.len() * 2 > dst.remaining_capacity() {
+        let err = storage_full();
+
+        return Err(err);
+    }
+
+    for (&left, &right) in left.iter().zip(right) {
+        dst.push(left)?;
+        dst.push(right)?;
+    }
+
+    Ok(())
+}
+
+/// Appends the even samples of `src` to `left` and the odd ones to `right`.
+/// returns `Err` with `InvalidInput` if `src` has an odd length
+/// and `StorageFull` if they do not fit, in which case nothing is appended.
+pub fn deinterleave<T: Copy, const L: usize, const R: usize>(
+    src: &[T],
+    left: &mut FlowerPot<T, L>,
+    right: &mut FlowerPot<T, R>,
+) -> Result<()> {
+    if !src.len().is_multiple_of(2) {
+        let err = invalid_input();
+
+        return Err(err);
+    }
+
+    let half = src.len() / 2;
+
+    if half > left.remaining_capacity() || half > right.remaining_capacity() {
+        let err = storage_full();
+
+        return Err(err);
+    }
+
+    for pair in src.chunks_exact(2) {
+        left.push(pair[0])?;
+        right.push(pair[1])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+//! Recovery of `FlowerLog`s from memory dumps.
+//!
+//! Every log starts with a magic number and a layout version,
+//! so it can be located in a dump taken after a crash and decoded on the host.
+
+use crate::log::{self, BUF_OFFSET, HEADER, MAGIC, VERSION};
+use crate::{LogRecord, invalid_data, unexpected_eof};
+use std::io::Result;
+
+/// `FlowerLog` recovered from a memory dump.
+#[derive(Debug, Clone, Copy)]
+pub struct DumpedLog<'a> {
+    buf: &'a [u8],
+    head: usize,
+    records: usize,
+}
+
+impl<'a> DumpedLog<'a> {
+    /// Returns the capacity the log had in bytes.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns the amount of records.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.records
+    }
+
+    /// Returns `true` if there are no records.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.records == 0
+    }
+
+    /// Iterates over the records from the oldest to the newest.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = LogRecord<'a>> + use<'a> {
+        log::records(self.buf, self.head, self.records)
+    }
+}
+
+/// Decodes the `FlowerLog` at the start of `bytes`.
+/// returns `Err` with `InvalidData` if it does not start with a log
+/// or the log is corrupted, and `UnexpectedEof` if `bytes` ends before the log does.
+pub fn parse(bytes: &[u8]) -> Result<DumpedLog<'_>> {
+    if bytes.len() < BUF_OFFSET {
+        let err = unexpected_eof();
+
+        return Err(err);
+    }
+
+    let field = |index: usize| {
+        let start = MAGIC.len() + index * 4;
+
+        let field = bytes[start..].first_chunk().copied().unwrap_or_default();
+
+        u32::from_le_bytes(field) as usize
+    };
+
+    if bytes[..MAGIC.len()] != MAGIC || field(0) != VERSION as usize {
+        let err = invalid_data();
+
+        return Err(err);
+    }
+
+    let (capacity, head, used, records) = (field(1), field(2), field(3), field(4));
+
+    let Some(buf) = bytes[BUF_OFFSET..].get(..capacity) else {
+        let err = unexpected_eof();
+
+        return Err(err);
+    };
+
+    let log = DumpedLog { buf, head, records };
+
+    if !is_consistent(&log, used) {
+        let err = invalid_data();
+
+        return Err(err);
+    }
+
+    Ok(log)
+}
+
+/// Returns `true` if the records of `log` take up exactly `used` bytes.
+fn is_consistent(log: &DumpedLog<'_>, used: usize) -> bool {
+    if used > log.capacity() || (log.records > 0 && log.head >= log.capacity()) {
+        return false;
+    }
+
+    let mut offset = log.head;
+    let mut remaining = used;
+
+    for _ in 0..log.records {
+        let Some(size) = remaining.checked_sub(HEADER) else {
+            return false;
+        };
+
+        let len = u16::from_le_bytes([
+            log.buf[offset % log.capacity()],
+            log.buf[(offset + 1) % log.capacity()],
+        ]) as usize;
+
+        let Some(rest) = size.checked_sub(len) else {
+            return false;
+        };
+
+        remaining = rest;
+        offset = (offset + HEADER + len) % log.capacity();
+    }
+
+    remaining == 0
+}
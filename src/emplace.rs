@@ -0,0 +1,210 @@
+#[cfg(not(feature = "forbid-unsafe-api"))]
+use crate::CollectError;
+use crate::{FlowerPot, Pod, storage_full};
+use std::io::Result;
+use std::mem::MaybeUninit;
+
+/// Handle to the next free slot of a `FlowerPot`, returned by `FlowerPot::reserve`.
+/// the value is constructed in place through `write` or `as_uninit_mut`,
+/// and pushed when the handle is dropped.
+/// if no value was written, nothing is pushed.
+pub struct SlotWriter<'a, T, const N: usize> {
+    pot: &'a mut FlowerPot<T, N>,
+    init: bool,
+}
+
+impl<T, const N: usize> SlotWriter<'_, T, N> {
+    /// Writes `item` into the slot, dropping a value written before.
+    /// returns a mutable reference to it.
+    pub fn write(&mut self, item: T) -> &mut T {
+        let slot = &mut self.pot.items[self.pot.pos];
+
+        if self.init {
+            // SAFETY: `init` is only set once the slot holds a value.
+            unsafe { slot.assume_init_drop() };
+        }
+
+        self.init = true;
+
+        slot.write(item)
+    }
+
+    /// Obtains a mutable reference to the uninitialized slot.
+    /// a value written before is leaked,
+    /// and nothing is pushed unless `assume_init` is called afterwards.
+    pub fn as_uninit_mut(&mut self) -> &mut MaybeUninit<T> {
+        self.init = false;
+
+        &mut self.pot.items[self.pot.pos]
+    }
+
+    /// Marks the slot as holding a value, so it is pushed on drop.
+    /// `write_zeroed` is the checked counterpart for `Pod` types.
+    ///
+    /// # Safety
+    /// the slot must have been fully initialized through `as_uninit_mut`.
+    #[cfg(not(feature = "forbid-unsafe-api"))]
+    pub unsafe fn assume_init(&mut self) {
+        self.init = true;
+    }
+
+    /// Returns `true` if a value will be pushed on drop.
+    #[inline]
+    pub fn is_init(&self) -> bool {
+        self.init
+    }
+}
+
+impl<T: Pod, const N: usize> SlotWriter<'_, T, N> {
+    /// Fills the slot with zero bytes in place.
+    /// returns a mutable reference to the zeroed value.
+    pub fn write_zeroed(&mut self) -> &mut T {
+        // Any value written before is leaked, `Pod` types are `Copy` and need no drop.
+        self.init = true;
+
+        let slot = &mut self.pot.items[self.pot.pos];
+
+        // SAFETY: `Pod` types have no invalid bit patterns, so zero bytes are a valid value.
+        unsafe {
+            slot.as_mut_ptr().write_bytes(0, 1);
+            slot.assume_init_mut()
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for SlotWriter<'_, T, N> {
+    fn drop(&mut self) {
+        if self.init {
+            self.pot.pos += 1;
+            self.pot.record_len();
+        }
+    }
+}
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Reserves the next free slot, so a value can be constructed in place
+    /// instead of being moved in through `push`.
+    /// returns `Err` with `StorageFull` if the `FlowerPot` is full.
+    pub fn reserve(&mut self) -> Result<SlotWriter<'_, T, N>> {
+        if self.is_full() {
+            self.record_rejected();
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        Ok(SlotWriter {
+            pot: self,
+            init: false,
+        })
+    }
+    /// Pushes an item initialized in place by `f`.
+    /// returns `Err` with `StorageFull` if the `FlowerPot` is full, in which case `f` is not called.
+    /// if `f` panics, nothing is pushed.
+    ///
+    /// # Safety
+    /// `f` must fully initialize the slot it is given.
+    #[cfg(not(feature = "forbid-unsafe-api"))]
+    pub unsafe fn push_with<F: FnOnce(&mut MaybeUninit<T>)>(&mut self, f: F) -> Result<()> {
+        let mut slot = self.reserve()?;
+        f(slot.as_uninit_mut());
+
+        // SAFETY: the caller guarantees `f` initialized the slot.
+        unsafe { slot.assume_init() };
+
+        Ok(())
+    }
+
+    /// Pushes an item initialized in place by `f`, which may fail.
+    /// returns `Err` with `StorageFull` if the `FlowerPot` is full, in which case `f` is not called,
+    /// or the error of `f`, in which case nothing is pushed.
+    ///
+    /// # Safety
+    /// `f` must fully initialize the slot it is given if it returns `Ok`.
+    #[cfg(not(feature = "forbid-unsafe-api"))]
+    pub unsafe fn try_push_with<E, F>(&mut self, f: F) -> std::result::Result<(), CollectError<E>>
+    where
+        F: FnOnce(&mut MaybeUninit<T>) -> std::result::Result<(), E>,
+    {
+        let mut slot = self.reserve().map_err(|_| CollectError::StorageFull)?;
+        f(slot.as_uninit_mut()).map_err(CollectError::Item)?;
+
+        // SAFETY: the caller guarantees `f` initialized the slot when it returned `Ok`.
+        unsafe { slot.assume_init() };
+
+        Ok(())
+    }
+
+    /// Pops the last item by moving it straight into `dst`,
+    /// a value already in `dst` is leaked.
+    /// returns `false` if the `FlowerPot` is empty, in which case `dst` is left alone.
+    pub fn pop_into(&mut self, dst: &mut MaybeUninit<T>) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        self.pos -= 1;
+        self.check_invariants();
+
+        // SAFETY: the item at the old last index is initialized
+        // and no longer part of the `FlowerPot`, so it is moved exactly once.
+        unsafe {
+            std::ptr::copy_nonoverlapping(self.items[self.pos].as_ptr(), dst.as_mut_ptr(), 1)
+        };
+
+        true
+    }
+
+    /// Removes the item at an specified index by moving it straight into `dst`,
+    /// shifting every item after it to the left.
+    /// a value already in `dst` is leaked.
+    /// returns `false` if that index is out of bounds, in which case `dst` is left alone.
+    pub fn read_index_into(&mut self, index: usize, dst: &mut MaybeUninit<T>) -> bool {
+        if index >= self.pos {
+            return false;
+        }
+
+        // SAFETY: `index < pos`, so the item is initialized
+        // and the range `index + 1..pos` is within the bounds.
+        unsafe {
+            let ptr = self.items.as_mut_ptr().add(index);
+            std::ptr::copy_nonoverlapping((*ptr).as_ptr(), dst.as_mut_ptr(), 1);
+            std::ptr::copy(ptr.add(1), ptr, self.pos - index - 1);
+        }
+
+        self.pos -= 1;
+        self.check_invariants();
+
+        true
+    }
+}
+
+impl<T: Pod, const N: usize> FlowerPot<T, N> {
+    /// Pushes an item zeroed in place and then filled in by `f`,
+    /// the checked counterpart of `push_with`.
+    /// returns `Err` with `StorageFull` if the `FlowerPot` is full, in which case `f` is not called.
+    /// if `f` panics, nothing is pushed.
+    pub fn push_zeroed_with<F: FnOnce(&mut T)>(&mut self, f: F) -> Result<()> {
+        if self.is_full() {
+            self.record_rejected();
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        let slot = &mut self.items[self.pos];
+
+        // SAFETY: `Pod` types have no invalid bit patterns, so zero bytes are a valid value.
+        let item = unsafe {
+            slot.as_mut_ptr().write_bytes(0, 1);
+            slot.assume_init_mut()
+        };
+
+        // The length only grows once `f` returns, so nothing is pushed if it panics.
+        f(item);
+        self.pos += 1;
+        self.record_len();
+
+        Ok(())
+    }
+}
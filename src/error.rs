@@ -0,0 +1,42 @@
+use crate::storage_full;
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Error returned when a value does not fit,
+/// handing it back to the caller instead of dropping it.
+pub struct CapacityError<T> {
+    value: T,
+}
+
+impl<T> CapacityError<T> {
+    pub(crate) const fn new(value: T) -> CapacityError<T> {
+        Self { value }
+    }
+
+    /// Obtains an immutable reference to the value that did not fit.
+    pub const fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the value that did not fit.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("`FlowerPot` capacity exceeded")
+    }
+}
+
+impl<T: fmt::Debug> Error for CapacityError<T> {}
+
+impl<T> From<CapacityError<T>> for io::Error {
+    /// Converts into an error with `StorageFull`, dropping the value.
+    fn from(_: CapacityError<T>) -> io::Error {
+        storage_full()
+    }
+}
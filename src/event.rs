@@ -0,0 +1,94 @@
+use crate::{FlowerPot, invalid_input};
+use std::io::Result;
+
+/// Mailbox of events with `LEVELS` priorities,
+/// holding up to `N` events per priority in arrival order.
+/// priority `0` is the highest and is popped first,
+/// but a waiting lane passed over `patience` times is popped next,
+/// so lower priorities are never starved.
+pub struct FlowerEventQueue<E, const N: usize, const LEVELS: usize> {
+    lanes: [FlowerPot<E, N>; LEVELS],
+    /// Amount of pops that passed over each waiting lane.
+    skipped: [usize; LEVELS],
+    patience: usize,
+}
+
+impl<E, const N: usize, const LEVELS: usize> FlowerEventQueue<E, N, LEVELS> {
+    /// Creates a new empty `FlowerEventQueue`
+    /// popping a lane after it was passed over `patience` times.
+    /// a `patience` of `usize::MAX` pops in strict priority order.
+    pub fn new(patience: usize) -> FlowerEventQueue<E, N, LEVELS> {
+        Self {
+            lanes: std::array::from_fn(|_| FlowerPot::new()),
+            skipped: [0; LEVELS],
+            patience,
+        }
+    }
+
+    /// Pushes an event to the lane of `priority`.
+    /// returns `Err` with `InvalidInput` if `priority` is not below `LEVELS`
+    /// and `StorageFull` if that lane is full.
+    pub fn push(&mut self, event: E, priority: usize) -> Result<()> {
+        let Some(lane) = self.lanes.get_mut(priority) else {
+            let err = invalid_input();
+
+            return Err(err);
+        };
+
+        lane.push(event)
+    }
+
+    /// Pops the oldest event of the highest priority,
+    /// or of a lane that ran out of patience.
+    /// returns `None` if there are no events.
+    pub fn pop(&mut self) -> Option<E> {
+        self.pop_with_priority().map(|(_, event)| event)
+    }
+
+    /// Pops like `pop` and also returns the priority of the event.
+    pub fn pop_with_priority(&mut self) -> Option<(usize, E)> {
+        let waiting = |level: &usize| !self.lanes[*level].is_empty();
+
+        let priority = (0..LEVELS)
+            .filter(waiting)
+            .find(|level| self.skipped[*level] >= self.patience)
+            .or_else(|| (0..LEVELS).find(waiting))?;
+
+        for level in 0..LEVELS {
+            if level != priority && !self.lanes[level].is_empty() {
+                self.skipped[level] = self.skipped[level].saturating_add(1);
+            }
+        }
+
+        self.skipped[priority] = 0;
+
+        let event = self.lanes[priority].remove(0)?;
+
+        Some((priority, event))
+    }
+
+    /// Returns the amount of events of every priority.
+    pub fn len(&self) -> usize {
+        self.lanes.iter().map(FlowerPot::len).sum()
+    }
+
+    /// Returns `true` if there are no events.
+    pub fn is_empty(&self) -> bool {
+        self.lanes.iter().all(FlowerPot::is_empty)
+    }
+
+    /// Returns the amount of events of `priority`,
+    /// which is `0` if `priority` is not below `LEVELS`.
+    pub fn len_at(&self, priority: usize) -> usize {
+        self.lanes.get(priority).map_or(0, FlowerPot::len)
+    }
+
+    /// Drops every event.
+    pub fn clear(&mut self) {
+        for lane in &mut self.lanes {
+            lane.clear();
+        }
+
+        self.skipped = [0; LEVELS];
+    }
+}
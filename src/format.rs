@@ -0,0 +1,105 @@
+use crate::{FlowerPot, FlowerString};
+use std::fmt::{self, Write};
+
+/// Outcome of formatting through `write_pot!`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WriteReport {
+    written: usize,
+    truncated: bool,
+}
+
+impl WriteReport {
+    /// Returns the amount of bytes that were written.
+    #[inline]
+    pub const fn written(&self) -> usize {
+        self.written
+    }
+
+    /// Returns `true` if the output did not fit and was cut off.
+    #[inline]
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+/// Fixed-capacity text buffer that `write_pot!` can format into.
+pub trait WritePot {
+    /// Appends as much of `s` as fits.
+    /// returns the amount of bytes appended.
+    fn write_truncating(&mut self, s: &str) -> usize;
+
+    /// Formats `args` into the buffer, cutting the output off when it is full.
+    /// this is what `write_pot!` expands to.
+    fn write_pot_fmt(&mut self, args: fmt::Arguments<'_>) -> WriteReport {
+        let mut adapter = Adapter {
+            dst: self,
+            report: WriteReport {
+                written: 0,
+                truncated: false,
+            },
+        };
+
+        // The only error of the adapter is truncation, which is recorded in the report.
+        let _ = adapter.write_fmt(args);
+
+        adapter.report
+    }
+}
+
+impl<const N: usize> WritePot for FlowerPot<u8, N> {
+    fn write_truncating(&mut self, s: &str) -> usize {
+        self.push_iter(s.bytes())
+    }
+}
+
+impl<const N: usize> WritePot for FlowerString<N> {
+    /// Never splits a character, so it may append less than what would fit.
+    fn write_truncating(&mut self, s: &str) -> usize {
+        let mut len = s.len().min(self.remaining_capacity());
+
+        while !s.is_char_boundary(len) {
+            len -= 1;
+        }
+
+        // Can not fail, `len` bytes fit.
+        let _ = self.push_str(&s[..len]);
+
+        len
+    }
+}
+
+impl<W: WritePot + ?Sized> WritePot for &mut W {
+    fn write_truncating(&mut self, s: &str) -> usize {
+        (**self).write_truncating(s)
+    }
+}
+
+struct Adapter<'a, W: ?Sized> {
+    dst: &'a mut W,
+    report: WriteReport,
+}
+
+impl<W: WritePot + ?Sized> Write for Adapter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let written = self.dst.write_truncating(s);
+        self.report.written += written;
+
+        if written < s.len() {
+            self.report.truncated = true;
+
+            return Err(fmt::Error);
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats into a `FlowerPot` of bytes or a `FlowerString` like `write!`,
+/// returning a `WriteReport` with the amount of bytes written
+/// and whether the output was truncated because it did not fit.
+#[macro_export]
+macro_rules! write_pot {
+    ($dst:expr, $($arg:tt)*) => {
+        $crate::WritePot::write_pot_fmt(&mut $dst, ::std::format_args!($($arg)*))
+    };
+}
@@ -0,0 +1,191 @@
+//! Length-prefixed and delimiter-terminated frames.
+//!
+//! `FrameBuilder` appends frames to a byte pot,
+//! `FrameParser` buffers incoming bytes until whole frames are available.
+
+use crate::{FlowerPot, SlotHandle, invalid_data};
+use std::io::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How frames are separated from each other.
+pub enum Framing {
+    /// Every frame starts with its length as a big-endian `u16`.
+    LengthPrefixed,
+    /// Every frame ends with the given delimiter byte,
+    /// which must not appear in its payload.
+    /// arbitrary binary payloads should be encoded with `cobs` or `slip` first.
+    Delimited(u8),
+}
+
+/// Guard that appends a single frame to a `FlowerPot` of bytes.
+/// the payload is written through `put_slice`,
+/// and dropped again if the guard is dropped without `finish`.
+pub struct FrameBuilder<'a, const N: usize> {
+    pot: &'a mut FlowerPot<u8, N>,
+    framing: Framing,
+    start: usize,
+    slot: Option<SlotHandle<2>>,
+}
+
+impl<'a, const N: usize> FrameBuilder<'a, N> {
+    /// Starts a new frame at the end of `pot`.
+    /// returns `Err` if the length prefix does not fit.
+    pub fn new(pot: &'a mut FlowerPot<u8, N>, framing: Framing) -> Result<FrameBuilder<'a, N>> {
+        let start = pot.len();
+        let slot = match framing {
+            Framing::LengthPrefixed => Some(pot.reserve_slot()?),
+            Framing::Delimited(_) => None,
+        };
+
+        Ok(Self {
+            pot,
+            framing,
+            start,
+            slot,
+        })
+    }
+
+    /// Appends `bytes` to the payload of the frame.
+    /// returns `Err` with `StorageFull` if they do not fit, in which case nothing is appended.
+    pub fn put_slice(&mut self, bytes: &[u8]) -> Result<()> {
+        self.pot.put_slice(bytes)
+    }
+
+    /// Obtains an immutable reference to the payload written so far.
+    pub fn payload(&self) -> &[u8] {
+        let header = if self.slot.is_some() { 2 } else { 0 };
+
+        &self.pot.get_init_slice()[self.start + header..]
+    }
+
+    /// Completes the frame, keeping it in the `FlowerPot`.
+    /// returns the length of the whole frame,
+    /// or `Err` with `InvalidData` if the payload is too long for the length prefix
+    /// or contains the delimiter, and `StorageFull` if the delimiter does not fit.
+    /// on error the frame is dropped.
+    pub fn finish(mut self) -> Result<usize> {
+        match self.framing {
+            Framing::LengthPrefixed => {
+                let Ok(len) = u16::try_from(self.payload().len()) else {
+                    let err = invalid_data();
+
+                    return Err(err);
+                };
+
+                let slot = self.slot.take().unwrap();
+                self.pot.fill_slot(slot, len.to_be_bytes())?;
+            }
+            Framing::Delimited(delimiter) => {
+                if self.payload().contains(&delimiter) {
+                    let err = invalid_data();
+
+                    return Err(err);
+                }
+
+                self.pot.push(delimiter)?;
+            }
+        }
+
+        let len = self.pot.len() - self.start;
+        self.start = self.pot.len();
+
+        Ok(len)
+    }
+}
+
+impl<const N: usize> Drop for FrameBuilder<'_, N> {
+    fn drop(&mut self) {
+        self.pot.truncate(self.start);
+    }
+}
+
+/// Splits a stream of bytes into frames,
+/// buffering up to `N` bytes of partial frames across reads.
+pub struct FrameParser<const N: usize> {
+    buf: FlowerPot<u8, N>,
+    framing: Framing,
+    /// Length of the last returned frame, dropped before the next operation.
+    pending: usize,
+}
+
+impl<const N: usize> FrameParser<N> {
+    /// Creates a new empty `FrameParser`.
+    pub fn new(framing: Framing) -> FrameParser<N> {
+        Self {
+            buf: FlowerPot::new(),
+            framing,
+            pending: 0,
+        }
+    }
+
+    /// Buffers as many bytes of `bytes` as fit.
+    /// returns the amount of bytes buffered,
+    /// the rest should be fed again after taking out frames.
+    pub fn feed(&mut self, bytes: &[u8]) -> usize {
+        self.release();
+
+        self.buf.push_iter(bytes.iter().copied())
+    }
+
+    /// Returns the payload of the next complete frame.
+    /// returns `Ok(None)` if more bytes are needed
+    /// and `Err` with `InvalidData` if the frame can never fit in the buffer,
+    /// in which case the parser should be cleared.
+    pub fn next_frame(&mut self) -> Result<Option<&[u8]>> {
+        self.release();
+
+        let bytes = self.buf.get_init_slice();
+
+        let (start, end, len) = match self.framing {
+            Framing::LengthPrefixed => {
+                let Some(len) = self.buf.get_u16_be(0) else {
+                    return Ok(None);
+                };
+
+                let end = 2 + len as usize;
+
+                if end > N {
+                    let err = invalid_data();
+
+                    return Err(err);
+                }
+
+                if end > bytes.len() {
+                    return Ok(None);
+                }
+
+                (2, end, end)
+            }
+            Framing::Delimited(delimiter) => match self.buf.position(delimiter) {
+                Some(end) => (0, end, end + 1),
+                None if self.buf.is_full() => {
+                    let err = invalid_data();
+
+                    return Err(err);
+                }
+                None => return Ok(None),
+            },
+        };
+
+        self.pending = len;
+
+        Ok(Some(&bytes[start..end]))
+    }
+
+    /// Obtains an immutable reference to the buffered bytes
+    /// that are not part of a returned frame.
+    pub fn buffered(&self) -> &[u8] {
+        &self.buf.get_init_slice()[self.pending..]
+    }
+
+    /// Drops every buffered byte.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.pending = 0;
+    }
+
+    fn release(&mut self) {
+        self.buf.consume(self.pending);
+        self.pending = 0;
+    }
+}
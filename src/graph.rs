@@ -0,0 +1,188 @@
+use crate::{FlowerPot, invalid_input, storage_full};
+use std::io::Result;
+
+/// Directed graph of `NODES` nodes, numbered from `0`,
+/// with up to `DEG` outgoing edges per node.
+/// the adjacency list of every node is a `FlowerPot`.
+pub struct FlowerGraph<const NODES: usize, const DEG: usize> {
+    adjacency: [FlowerPot<usize, DEG>; NODES],
+}
+
+impl<const NODES: usize, const DEG: usize> FlowerGraph<NODES, DEG> {
+    /// Creates a new `FlowerGraph` without edges.
+    pub fn new() -> FlowerGraph<NODES, DEG> {
+        Self {
+            adjacency: [const { FlowerPot::new() }; NODES],
+        }
+    }
+
+    /// Returns the amount of nodes, which is always `NODES`.
+    #[inline]
+    pub const fn node_count(&self) -> usize {
+        NODES
+    }
+
+    /// Returns the amount of edges.
+    pub fn edge_count(&self) -> usize {
+        self.adjacency.iter().map(FlowerPot::len).sum()
+    }
+
+    /// Adds an edge from `from` to `to`.
+    /// returns `false` if it was already there,
+    /// or `Err` with `InvalidInput` if either node is out of bounds
+    /// and `StorageFull` if `from` already has `DEG` edges.
+    pub fn add_edge(&mut self, from: usize, to: usize) -> Result<bool> {
+        if to >= NODES {
+            let err = invalid_input();
+
+            return Err(err);
+        }
+
+        let Some(edges) = self.adjacency.get_mut(from) else {
+            let err = invalid_input();
+
+            return Err(err);
+        };
+
+        edges.push_unique(to).map_err(|_| storage_full())
+    }
+
+    /// Adds edges in both directions between `a` and `b`.
+    /// returns `false` if both were already there,
+    /// or `Err` like `add_edge`, in which case neither is added.
+    pub fn add_undirected_edge(&mut self, a: usize, b: usize) -> Result<bool> {
+        let added = self.add_edge(a, b)?;
+
+        match self.add_edge(b, a) {
+            Ok(added_back) => Ok(added || added_back),
+            Err(err) => {
+                if added {
+                    self.remove_edge(a, b);
+                }
+
+                Err(err)
+            }
+        }
+    }
+
+    /// Removes the edge from `from` to `to`.
+    /// returns `false` if there is no such edge.
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> bool {
+        let Some(edges) = self.adjacency.get_mut(from) else {
+            return false;
+        };
+
+        edges.remove_by_key(to, |node| *node).is_some()
+    }
+
+    /// Returns `true` if there is an edge from `from` to `to`.
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.neighbors(from).contains(&to)
+    }
+
+    /// Obtains the nodes `node` has edges to, in the order they were added.
+    /// returns an empty slice if `node` is out of bounds.
+    pub fn neighbors(&self, node: usize) -> &[usize] {
+        self.adjacency
+            .get(node)
+            .map_or(&[], FlowerPot::get_init_slice)
+    }
+
+    /// Returns the amount of edges leaving `node`.
+    pub fn degree(&self, node: usize) -> usize {
+        self.neighbors(node).len()
+    }
+
+    /// Iterates over the nodes reachable from `start` in breadth-first order,
+    /// starting with `start` itself.
+    /// yields nothing if `start` is out of bounds.
+    pub fn bfs(&self, start: usize) -> Bfs<'_, NODES, DEG> {
+        let mut bfs = Bfs {
+            graph: self,
+            queue: [0; NODES],
+            head: 0,
+            tail: 0,
+            visited: [false; NODES],
+        };
+
+        bfs.enqueue(start);
+
+        bfs
+    }
+
+    /// Returns the amount of edges on the shortest path from `start` to every node,
+    /// `None` for nodes that are unreachable.
+    pub fn distances(&self, start: usize) -> [Option<usize>; NODES] {
+        let mut distances = [None; NODES];
+
+        if let Some(distance) = distances.get_mut(start) {
+            *distance = Some(0);
+        }
+
+        // Nodes are yielded in order of their distance,
+        // so the first path that reaches a node is a shortest one.
+        for node in self.bfs(start) {
+            let next = distances[node].map(|distance| distance + 1);
+
+            for &neighbor in self.neighbors(node) {
+                if distances[neighbor].is_none() {
+                    distances[neighbor] = next;
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Removes every edge.
+    pub fn clear(&mut self) {
+        for edges in &mut self.adjacency {
+            edges.clear();
+        }
+    }
+}
+
+impl<const NODES: usize, const DEG: usize> Default for FlowerGraph<NODES, DEG> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Iterator returned by `FlowerGraph::bfs`.
+pub struct Bfs<'a, const NODES: usize, const DEG: usize> {
+    graph: &'a FlowerGraph<NODES, DEG>,
+    /// Every node is queued at most once, so `NODES` slots never wrap around.
+    queue: [usize; NODES],
+    head: usize,
+    tail: usize,
+    visited: [bool; NODES],
+}
+
+impl<const NODES: usize, const DEG: usize> Bfs<'_, NODES, DEG> {
+    fn enqueue(&mut self, node: usize) {
+        if let Some(visited @ false) = self.visited.get_mut(node) {
+            *visited = true;
+            self.queue[self.tail] = node;
+            self.tail += 1;
+        }
+    }
+}
+
+impl<const NODES: usize, const DEG: usize> Iterator for Bfs<'_, NODES, DEG> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        if self.head == self.tail {
+            return None;
+        }
+
+        let node = self.queue[self.head];
+        self.head += 1;
+
+        for &neighbor in self.graph.neighbors(node) {
+            self.enqueue(neighbor);
+        }
+
+        Some(node)
+    }
+}
@@ -0,0 +1,174 @@
+use crate::FlowerPot;
+use std::borrow::Borrow;
+use std::io::Result;
+
+/// Map stored in a `FlowerPot` of key-value pairs in insertion order,
+/// can store up to `N` entries.
+/// entries can be looked up by key, scanning linearly, or by index.
+#[derive(Debug, Default)]
+pub struct FlowerIndexMap<K, V, const N: usize> {
+    entries: FlowerPot<(K, V), N>,
+}
+
+impl<K: Eq, V, const N: usize> FlowerIndexMap<K, V, N> {
+    /// Creates a new empty `FlowerIndexMap`.
+    pub fn new() -> FlowerIndexMap<K, V, N> {
+        Self {
+            entries: FlowerPot::new(),
+        }
+    }
+
+    /// Returns the amount of entries in the map.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map holds no entries.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if the map can not hold any more entries.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.entries.is_full()
+    }
+
+    /// Returns the total amount of entries the map can hold,
+    /// which is always `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the index of the entry of `key`.
+    /// returns `None` if the map holds no such entry.
+    pub fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.entries
+            .get_init_slice()
+            .iter()
+            .position(|(k, _)| k.borrow() == key)
+    }
+
+    /// Returns `true` if the map holds an entry for `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.get_index_of(key).is_some()
+    }
+
+    /// Obtains an immutable reference to the value of `key`.
+    /// returns `None` if the map holds no such entry.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+
+        self.entries.get(index).map(|(_, v)| v)
+    }
+
+    /// Obtains a mutable reference to the value of `key`.
+    /// returns `None` if the map holds no such entry.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+
+        self.entries.get_mut(index).map(|(_, v)| v)
+    }
+
+    /// Obtains the entry at an specified index.
+    /// returns `None` if that index is out of bounds.
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(|(k, v)| (k, v))
+    }
+
+    /// Obtains the entry at an specified index, with a mutable reference to the value.
+    /// returns `None` if that index is out of bounds.
+    pub fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut V)> {
+        self.entries.get_mut(index).map(|(k, v)| (&*k, v))
+    }
+
+    /// Inserts a value for `key`, returning the previous value if there was one.
+    /// existing keys keep their position, new keys are appended.
+    /// returns `Err` with `StorageFull` if `key` is new and the map is full.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>> {
+        match self.get_index_of(&key) {
+            Some(index) => Ok(Some(std::mem::replace(&mut self.entries[index].1, value))),
+            None => self.entries.push((key, value)).map(|_| None),
+        }
+    }
+
+    /// Removes the entry of `key` and returns its value,
+    /// the last entry is moved into its place.
+    /// returns `None` if the map holds no such entry.
+    pub fn swap_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+
+        self.entries.swap_remove(index).map(|(_, v)| v)
+    }
+
+    /// Removes the entry of `key` and returns its value,
+    /// shifting every entry after it to keep the insertion order.
+    /// returns `None` if the map holds no such entry.
+    pub fn shift_remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.get_index_of(key)?;
+
+        self.entries.remove(index).map(|(_, v)| v)
+    }
+
+    /// Removes the last entry and returns it.
+    /// returns `None` if the map is empty.
+    pub fn pop(&mut self) -> Option<(K, V)> {
+        self.entries.pop()
+    }
+
+    /// Drops every entry in the map.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns an iterator over the entries in insertion order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> {
+        self.entries.get_init_slice().iter().map(|(k, v)| (k, v))
+    }
+
+    /// Returns an iterator over the entries in insertion order,
+    /// with mutable references to the values.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (&K, &mut V)> {
+        self.entries
+            .get_init_slice_mut()
+            .iter_mut()
+            .map(|(k, v)| (&*k, v))
+    }
+
+    /// Returns an iterator over the keys in insertion order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over the values in insertion order.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+}
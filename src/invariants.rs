@@ -0,0 +1,28 @@
+use crate::FlowerPot;
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Asserts the bookkeeping of the `FlowerPot` in debug builds,
+    /// that is `pos <= N` and, with `metrics`, that the high water mark covers `pos`.
+    /// called through `record_len` by every operation that grows the length,
+    /// and by `pop`, `pop_array`, `remove`, `consume`, `pop_into`, `read_index_into`
+    /// and the recycle drop, which shrink it.
+    /// every other mutating operation, such as `insert`, `truncate` or `clear`, goes through those.
+    /// whether the items in `0..pos` are initialized can not be observed at runtime,
+    /// it is covered by the model test in the tests crate, and targeted by the Kani harnesses in `proofs`.
+    #[inline(always)]
+    pub(crate) fn check_invariants(&self) {
+        debug_assert!(
+            self.pos <= N,
+            "length {} exceeds the capacity {N}",
+            self.pos
+        );
+
+        #[cfg(feature = "metrics")]
+        debug_assert!(
+            self.high_water_mark() >= self.pos,
+            "length {} exceeds the high water mark {}",
+            self.pos,
+            self.high_water_mark()
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{FlowerPot, invalid_input, storage_full};
+use std::cmp::min;
+use std::io::{Read, Result, Seek, SeekFrom, Write};
+
+/// Cursor over a `FlowerPot` of bytes,
+/// implementing `Read`, `Write` and `Seek` on the inline buffer.
+/// writes overwrite existing bytes and extend the pot past its end,
+/// seeking past the end and writing fills the gap with zeroes.
+pub struct PotCursor<'a, const N: usize> {
+    pot: &'a mut FlowerPot<u8, N>,
+    pos: u64,
+}
+
+impl<'a, const N: usize> PotCursor<'a, N> {
+    /// Creates a new `PotCursor` over `pot`, positioned at the start.
+    pub fn new(pot: &'a mut FlowerPot<u8, N>) -> PotCursor<'a, N> {
+        Self { pot, pos: 0 }
+    }
+
+    /// Returns the current position of the cursor.
+    pub fn position(&self) -> u64 {
+        self.pos
+    }
+
+    /// Sets the position of the cursor,
+    /// it may point past the end of the pot.
+    pub fn set_position(&mut self, pos: u64) {
+        self.pos = pos;
+    }
+
+    /// Obtains an immutable reference to the underlying pot.
+    pub fn get_ref(&self) -> &FlowerPot<u8, N> {
+        self.pot
+    }
+
+    /// Obtains a mutable reference to the underlying pot.
+    pub fn get_mut(&mut self) -> &mut FlowerPot<u8, N> {
+        self.pot
+    }
+
+    /// Returns the bytes between the position and the end of the pot.
+    pub fn remaining_slice(&self) -> &[u8] {
+        let start = min(self.pos, self.pot.len() as u64) as usize;
+
+        &self.pot.get_init_slice()[start..]
+    }
+}
+
+impl<const N: usize> Read for PotCursor<'_, N> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let remaining = self.remaining_slice();
+        let len = min(remaining.len(), buf.len());
+
+        buf[..len].copy_from_slice(&remaining[..len]);
+        self.pos += len as u64;
+
+        Ok(len)
+    }
+}
+
+impl<const N: usize> Write for PotCursor<'_, N> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if buf.is_empty() {
+            return Ok(0);
+        }
+
+        if self.pos >= N as u64 {
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        let pos = self.pos as usize;
+
+        while self.pot.len() < pos {
+            self.pot.push(0)?;
+        }
+
+        let overwrite = min(self.pot.len() - pos, buf.len());
+        self.pot.get_init_slice_mut()[pos..pos + overwrite].copy_from_slice(&buf[..overwrite]);
+
+        let written = overwrite + self.pot.push_iter(buf[overwrite..].iter().copied());
+        self.pos += written as u64;
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<const N: usize> Seek for PotCursor<'_, N> {
+    fn seek(&mut self, style: SeekFrom) -> Result<u64> {
+        let (base, offset) = match style {
+            SeekFrom::Start(pos) => {
+                self.pos = pos;
+
+                return Ok(pos);
+            }
+            SeekFrom::End(offset) => (self.pot.len() as u64, offset),
+            SeekFrom::Current(offset) => (self.pos, offset),
+        };
+
+        match base.checked_add_signed(offset) {
+            Some(pos) => {
+                self.pos = pos;
+
+                Ok(pos)
+            }
+            None => {
+                let err = invalid_input();
+
+                Err(err)
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use crate::{FlowerMap, FlowerPot, FlowerString, WritePot, storage_full};
+use std::fmt::{self, Write};
+use std::io::Result;
+
+/// Value that can be rendered as JSON into a `FlowerPot` of bytes.
+/// floats that are not finite are rendered as `null`.
+pub trait ToJson {
+    /// Appends `self` rendered as JSON to `dst`.
+    /// returns `Err` with `StorageFull` if it does not fit,
+    /// in which case `dst` may hold part of the output.
+    fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()>;
+
+    /// Appends `self` rendered as JSON to `dst`.
+    /// returns `Err` with `StorageFull` if it does not fit, in which case nothing is appended.
+    fn to_json_into<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+        let start = dst.len();
+        let result = self.write_json(dst);
+
+        if result.is_err() {
+            dst.truncate(start);
+        }
+
+        result
+    }
+}
+
+macro_rules! display_json {
+    ($($ty:ty),*) => {
+        $(
+            impl ToJson for $ty {
+                fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+                    write_display(dst, self)
+                }
+            }
+        )*
+    };
+}
+
+display_json!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, bool
+);
+
+macro_rules! float_json {
+    ($($ty:ty),*) => {
+        $(
+            impl ToJson for $ty {
+                fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+                    if !self.is_finite() {
+                        return dst.put_slice(b"null");
+                    }
+
+                    write_display(dst, self)
+                }
+            }
+        )*
+    };
+}
+
+float_json!(f32, f64);
+
+impl ToJson for str {
+    fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+        write_string(dst, self)
+    }
+}
+
+impl ToJson for String {
+    fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+        write_string(dst, self)
+    }
+}
+
+impl<const N: usize> ToJson for FlowerString<N> {
+    fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+        write_string(dst, self)
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for &T {
+    fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+        (**self).write_json(dst)
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+        match self {
+            Some(value) => value.write_json(dst),
+            None => dst.put_slice(b"null"),
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+        dst.push(b'[')?;
+
+        for (index, item) in self.iter().enumerate() {
+            if index > 0 {
+                dst.push(b',')?;
+            }
+
+            item.write_json(dst)?;
+        }
+
+        dst.push(b']')
+    }
+}
+
+impl<T: ToJson, const N: usize> ToJson for FlowerPot<T, N> {
+    /// Renders the items as an array.
+    fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+        self.get_init_slice().write_json(dst)
+    }
+}
+
+impl<K: Eq + fmt::Display, V: ToJson, const N: usize> ToJson for FlowerMap<K, V, N> {
+    /// Renders the entries as an object, with the keys formatted with `Display`.
+    fn write_json<const M: usize>(&self, dst: &mut FlowerPot<u8, M>) -> Result<()> {
+        dst.push(b'{')?;
+
+        for (index, (key, value)) in self.iter().enumerate() {
+            if index > 0 {
+                dst.push(b',')?;
+            }
+
+            write_string(dst, key)?;
+            dst.push(b':')?;
+            value.write_json(dst)?;
+        }
+
+        dst.push(b'}')
+    }
+}
+
+fn write_display<T: fmt::Display + ?Sized, const M: usize>(
+    dst: &mut FlowerPot<u8, M>,
+    value: &T,
+) -> Result<()> {
+    if dst.write_pot_fmt(format_args!("{value}")).is_truncated() {
+        let err = storage_full();
+
+        return Err(err);
+    }
+
+    Ok(())
+}
+
+/// Writes `value` formatted with `Display` as a quoted and escaped string.
+fn write_string<T: fmt::Display + ?Sized, const M: usize>(
+    dst: &mut FlowerPot<u8, M>,
+    value: &T,
+) -> Result<()> {
+    dst.push(b'"')?;
+
+    if write!(Escaper { dst }, "{value}").is_err() {
+        let err = storage_full();
+
+        return Err(err);
+    }
+
+    dst.push(b'"')
+}
+
+/// Escapes everything written through it for a JSON string.
+struct Escaper<'a, const M: usize> {
+    dst: &'a mut FlowerPot<u8, M>,
+}
+
+impl<const M: usize> fmt::Write for Escaper<'_, M> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        for c in s.chars() {
+            let result = match c {
+                '"' => self.dst.put_slice(b"\\\""),
+                '\\' => self.dst.put_slice(b"\\\\"),
+                '\n' => self.dst.put_slice(b"\\n"),
+                '\r' => self.dst.put_slice(b"\\r"),
+                '\t' => self.dst.put_slice(b"\\t"),
+                c if c < ' ' => write_display(self.dst, &format_args!("\\u{:04x}", c as u32)),
+                c => self.dst.put_slice(c.encode_utf8(&mut [0; 4]).as_bytes()),
+            };
+
+            result.map_err(|_| fmt::Error)?;
+        }
+
+        Ok(())
+    }
+}
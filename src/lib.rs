@@ -1,5 +1,147 @@
+use std::borrow::{Borrow, BorrowMut};
 use std::io::{self, Result};
 use std::mem::MaybeUninit;
+use std::slice::SliceIndex;
+
+pub mod cobs;
+pub mod dsp;
+pub mod dump;
+pub mod frame;
+pub mod prelude;
+pub mod slip;
+
+mod adapters;
+mod aliases;
+#[cfg(feature = "allocator")]
+mod allocator;
+mod assert;
+mod atomic;
+mod bits;
+mod bloom;
+mod budget;
+mod bytes;
+#[cfg(feature = "alloc")]
+mod chain;
+mod checksum;
+mod collect;
+mod cow;
+mod cursor;
+mod debounce;
+mod double;
+mod emplace;
+mod error;
+mod event;
+mod format;
+mod graph;
+mod index_map;
+mod invariants;
+mod io_cursor;
+#[cfg(feature = "json")]
+mod json;
+mod lines;
+mod log;
+mod map;
+mod merge;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod min_max_heap;
+mod multi_map;
+mod nested;
+mod niche;
+mod overflow;
+mod partition;
+mod pipe;
+mod pod;
+mod pool;
+mod pot;
+#[cfg(all(kani, feature = "verification"))]
+mod proofs;
+mod rate;
+mod record;
+mod recycle;
+mod reduce;
+mod rle;
+mod scoped;
+mod section;
+mod seqlock;
+mod sharded;
+mod simd;
+mod sorted_map;
+mod sparse;
+mod steal;
+mod str_list;
+mod string;
+mod top_k;
+mod transaction;
+mod try_clone;
+mod try_extend;
+mod unique;
+mod utf8;
+#[cfg(feature = "alloc")]
+mod vec;
+mod watermark;
+mod windows;
+mod writer;
+
+pub use adapters::{ChunksPot, IteratorExt, Lookahead};
+pub use aliases::{Line, Page, SmallPot4, SmallPot8, SmallPot16};
+#[cfg(feature = "allocator")]
+pub use allocator::FlowerPotAllocator;
+pub use assert::CapacityAssert;
+pub use atomic::AtomicFlowerPot;
+pub use bits::{BitOrder, BitReader, BitWriter};
+pub use bloom::FlowerBloom;
+pub use budget::capacity_for_bytes;
+pub use bytes::SlotHandle;
+#[cfg(feature = "alloc")]
+pub use chain::PotChain;
+pub use checksum::{Checksum, ChecksumPot, Crc32, Fletcher16};
+pub use collect::CollectError;
+pub use cow::PotOrSlice;
+pub use cursor::CursorMut;
+pub use debounce::Debouncer;
+pub use double::{Back, DoubleBuffer, Front};
+pub use emplace::SlotWriter;
+pub use error::CapacityError;
+pub use event::FlowerEventQueue;
+pub use format::{WritePot, WriteReport};
+pub use graph::{Bfs, FlowerGraph};
+pub use index_map::FlowerIndexMap;
+pub use io_cursor::PotCursor;
+#[cfg(feature = "json")]
+pub use json::ToJson;
+pub use lines::{LineBuffer, Overlong};
+pub use log::{FlowerLog, LogRecord};
+pub use map::{Entry, FlowerMap, OccupiedEntry, VacantEntry};
+pub use min_max_heap::FlowerMinMaxHeap;
+pub use multi_map::FlowerMultiMap;
+pub use nested::FlatPots;
+pub use niche::OptionPot;
+pub use overflow::FlowerPotWithOverflow;
+pub use pipe::{FlowerPipe, PipeConsumer, PipeProducer, ReadGrant, WriteGrant};
+pub use pod::Pod;
+pub use pool::{PooledPotChain, PotPool};
+pub use pot::{DynPot, Pot};
+pub use rate::RateLimiter;
+pub use record::RecordWriter;
+pub use recycle::RecycleIter;
+pub use rle::FlowerRle;
+pub use scoped::PopGuard;
+pub use section::StaticPot;
+pub use seqlock::{SeqLockPot, SeqLockReader, SeqLockWriter};
+pub use sharded::ShardedPots;
+pub use sorted_map::FlowerSortedMap;
+pub use sparse::SparseFlowerPot;
+pub use steal::{DequeStealer, DequeWorker, FlowerWorkDeque};
+pub use str_list::FlowerStrList;
+pub use string::FlowerString;
+pub use top_k::TopK;
+pub use transaction::Transaction;
+pub use try_clone::TryClone;
+pub use try_extend::{TryCollectInto, TryExtend};
+pub use utf8::Utf8Accumulator;
+pub use watermark::{WatermarkEvent, Watermarks};
+pub use writer::ChunkedWriter;
 
 #[derive(Debug)]
 /// Pre-allocated stack storage
@@ -8,48 +150,111 @@ use std::mem::MaybeUninit;
 pub struct FlowerPot<T, const N: usize> {
     items: [MaybeUninit<T>; N],
     pos: usize,
+    #[cfg(feature = "metrics")]
+    metrics: metrics::Metrics,
 }
 
 impl<T, const N: usize> FlowerPot<T, N> {
     /// Creates a new `FlowerPot`
     /// with the `pos` field set to 0.
-    pub fn new() -> FlowerPot<T, N> {
+    pub const fn new() -> FlowerPot<T, N> {
         let items = [const { MaybeUninit::uninit() }; N];
 
-        Self { items, pos: 0 }
+        Self {
+            items,
+            pos: 0,
+            #[cfg(feature = "metrics")]
+            metrics: metrics::Metrics::new(),
+        }
+    }
+
+    /// Creates a new `FlowerPot` from the items of `iter`.
+    /// returns `Err` with `StorageFull` if the iterator yields more than `N` items.
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<FlowerPot<T, N>> {
+        let mut pot = Self::new();
+
+        if pot.push_iter_rest(iter).next().is_some() {
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        Ok(pot)
+    }
+
+    /// Creates a new `FlowerPot` from the items of `iter`,
+    /// which must yield exactly `N` items.
+    /// returns `Err` with `StorageFull` if the iterator yields more
+    /// and `UnexpectedEof` if it yields fewer.
+    pub fn from_iter_exact<I: IntoIterator<Item = T>>(iter: I) -> Result<FlowerPot<T, N>> {
+        let pot = Self::try_from_iter(iter)?;
+
+        if !pot.is_full() {
+            let err = unexpected_eof();
+
+            return Err(err);
+        }
+
+        Ok(pot)
     }
 
     /// Returns `true` if `pos` is bigger than or equal to `N`
     /// else returns `false`.
     #[inline]
+    #[deprecated(note = "use `is_full` instead")]
     pub const fn full(&self) -> bool {
-        self.pos >= N
+        self.is_full()
     }
 
     /// Returns `true` if `pos` is equal to 0.
     /// else returns false.
     #[inline]
+    #[deprecated(note = "use `is_empty` instead")]
     pub const fn empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    /// Returns `true` if `pos` is bigger than or equal to `N`
+    /// else returns `false`.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.pos >= N
+    }
+
+    /// Returns `true` if `pos` is equal to 0.
+    /// else returns false.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
         self.pos == 0
     }
 
-    /// Returns the current amount of used space,
-    /// the current implementation uses `checked_sub` on `pos`
-    /// returning `0` on `None` and the value on `Some`.
+    /// Returns the current amount of used space.
     #[inline]
-    pub fn len(&self) -> usize {
-        match self.pos.checked_sub(1) {
-            None => 0,
-            Some(num) => num,
-        }
+    pub const fn len(&self) -> usize {
+        self.pos
+    }
+
+    /// Returns the total amount of items the `FlowerPot` can hold,
+    /// which is always `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the amount of items that can still be pushed
+    /// before the `FlowerPot` is full.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        N - self.pos
     }
 
     /// Pushes an item to the `FlowerPot`.
     /// returns `Ok` if the operation was successful.
     /// if the container is full, returns `Err`
     pub fn push(&mut self, item: T) -> Result<()> {
-        if self.full() {
-            let err = io::Error::from(io::ErrorKind::StorageFull);
+        if self.is_full() {
+            self.record_rejected();
+            let err = storage_full();
 
             return Err(err);
         }
@@ -61,13 +266,45 @@ impl<T, const N: usize> FlowerPot<T, N> {
             self.pos += 1
         }
 
+        self.record_len();
+
         Ok(())
     }
 
+    /// Pushes items from `iter` until the `FlowerPot` is full.
+    /// returns the amount of items that were pushed,
+    /// items past the capacity are never pulled from the iterator.
+    pub fn push_iter<I: IntoIterator<Item = T>>(&mut self, iter: I) -> usize {
+        let start = self.pos;
+        let _ = self.push_iter_rest(iter);
+
+        self.pos - start
+    }
+
+    /// Pushes items from `iter` until the `FlowerPot` is full.
+    /// returns the iterator with the items that did not fit.
+    pub fn push_iter_rest<I: IntoIterator<Item = T>>(&mut self, iter: I) -> I::IntoIter {
+        let mut iter = iter.into_iter();
+
+        while !self.is_full() {
+            let Some(item) = iter.next() else {
+                break;
+            };
+
+            // SAFETY: The `FlowerPot` is not full, so `pos` is within the bounds.
+            unsafe { (*self.items.as_mut_ptr().add(self.pos)).write(item) };
+            self.pos += 1;
+        }
+
+        self.record_len();
+
+        iter
+    }
+
     /// Pops an item from the `FlowerPot`.
     /// returns `None` if the container is empty.
     pub fn pop(&mut self) -> Option<T> {
-        if self.empty() {
+        if self.is_empty() {
             return None;
         }
 
@@ -78,13 +315,179 @@ impl<T, const N: usize> FlowerPot<T, N> {
             maybe.assume_init_read()
         };
 
+        self.check_invariants();
+
         Some(val)
     }
 
+    /// Pushes every item of `items` in order.
+    /// returns `Err` with `StorageFull` if they do not fit, in which case nothing is pushed.
+    pub fn push_array<const K: usize>(&mut self, items: [T; K]) -> Result<()> {
+        if K > self.remaining_capacity() {
+            self.record_rejected();
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        // SAFETY: there are at least `K` free slots after `pos`,
+        // and `[MaybeUninit<T>; K]` has the same layout as `[T; K]`.
+        unsafe {
+            self.items
+                .as_mut_ptr()
+                .add(self.pos)
+                .cast::<[T; K]>()
+                .write(items)
+        };
+        self.pos += K;
+
+        self.record_len();
+
+        Ok(())
+    }
+
+    /// Pops the last `K` items from the `FlowerPot`, keeping their order.
+    /// returns `None` if it holds fewer than `K` items, in which case nothing is popped.
+    pub fn pop_array<const K: usize>(&mut self) -> Option<[T; K]> {
+        if K > self.pos {
+            return None;
+        }
+
+        self.pos -= K;
+        self.check_invariants();
+
+        // SAFETY: the `K` slots after `pos` were initialized,
+        // and are no longer part of the `FlowerPot`.
+        Some(unsafe { self.items.as_ptr().add(self.pos).cast::<[T; K]>().read() })
+    }
+
+    /// Obtains an immutable reference to the last `k` items.
+    /// returns `None` if the `FlowerPot` holds fewer than `k` items.
+    pub fn peek_n(&self, k: usize) -> Option<&[T]> {
+        let start = self.pos.checked_sub(k)?;
+
+        Some(&self.get_init_slice()[start..])
+    }
+
+    /// Inserts an item at an specified index,
+    /// shifting every item after it to the right.
+    /// returns `Err` with `StorageFull` if the container is full
+    /// and `InvalidInput` if `index` is bigger than `len()`.
+    pub fn insert(&mut self, index: usize, item: T) -> Result<()> {
+        if index > self.pos {
+            let err = invalid_input();
+
+            return Err(err);
+        }
+
+        if self.is_full() {
+            self.record_rejected();
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        // SAFETY: `index <= pos < N`, so both the source range `index..pos`
+        // and the destination range `index + 1..pos + 1` are within the bounds.
+        unsafe {
+            let ptr = self.items.as_mut_ptr().add(index);
+            std::ptr::copy(ptr, ptr.add(1), self.pos - index);
+            (*ptr).write(item);
+        }
+
+        self.pos += 1;
+        self.record_len();
+
+        Ok(())
+    }
+
+    /// Removes the item at an specified index and returns it,
+    /// shifting every item after it to the left.
+    /// returns `None` if that index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.pos {
+            return None;
+        }
+
+        // SAFETY: `index < pos`, so the item is initialized
+        // and the range `index + 1..pos` is within the bounds.
+        let val = unsafe {
+            let ptr = self.items.as_mut_ptr().add(index);
+            let val = (*ptr).assume_init_read();
+            std::ptr::copy(ptr.add(1), ptr, self.pos - index - 1);
+
+            val
+        };
+
+        self.pos -= 1;
+        self.check_invariants();
+
+        Some(val)
+    }
+
+    /// Obtains a cursor pointing at the first item of the `FlowerPot`.
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T, N> {
+        CursorMut::new(self)
+    }
+
+    /// Drops every item in the `FlowerPot`,
+    /// setting `pos` back to 0.
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+
+    /// Drops every item past the first `len` items,
+    /// does nothing if `len` is bigger than or equal to `pos`.
+    pub fn truncate(&mut self, len: usize) {
+        while self.pos > len {
+            self.pop();
+        }
+    }
+
+    /// Drops the first `n` items and shifts the remaining ones to the front,
+    /// drops every item if `n` is bigger than `len()`.
+    pub fn consume(&mut self, n: usize) {
+        let len = self.pos;
+        let n = n.min(len);
+
+        // Forget every item while dropping, so a panicking `Drop`
+        // can only leak items instead of dropping them twice.
+        self.pos = 0;
+
+        // SAFETY: the first `n` items are initialized and dropped exactly once,
+        // afterwards the initialized range `n..len` is moved to the front.
+        unsafe {
+            let ptr = self.items.as_mut_ptr().cast::<T>();
+            std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(ptr, n));
+            std::ptr::copy(ptr.add(n), ptr, len - n);
+        }
+
+        self.pos = len - n;
+        self.check_invariants();
+    }
+
+    /// Records the current length of the `FlowerPot`,
+    /// which can later be passed to `restore`.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint { len: self.pos }
+    }
+
+    /// Drops every item pushed since `checkpoint` was taken.
+    /// does nothing if the `FlowerPot` is shorter than it was at that point.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        self.truncate(checkpoint.len);
+    }
+
+    /// Starts a transaction through which pushes are staged,
+    /// staged items are dropped unless the transaction is committed.
+    pub fn transaction(&mut self) -> Transaction<'_, T, N> {
+        Transaction::new(self)
+    }
+
     /// Obtains an immutable reference to an item at an specified index.
     /// returns `None` if that index is out of bounds.
     pub fn get(&self, index: usize) -> Option<&T> {
-        if index > self.pos {
+        if index >= self.pos {
             return None;
         }
 
@@ -98,7 +501,7 @@ impl<T, const N: usize> FlowerPot<T, N> {
     /// Obtains a mutable reference to an item at an specified index.
     /// returns `None` if that index is out of bounds.
     pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index > self.pos {
+        if index >= self.pos {
             return None;
         }
 
@@ -112,16 +515,137 @@ impl<T, const N: usize> FlowerPot<T, N> {
 
     /// Obtains an immutable reference to an item at an specified index.
     /// Does not check if the memory at the index is initialized.
+    ///
+    /// # Safety
+    /// `index` must be smaller than `len()`.
+    #[cfg(not(feature = "forbid-unsafe-api"))]
     pub unsafe fn get_unchecked(&mut self, index: usize) -> &T {
         unsafe { &mut *(self.items.as_ptr().add(index) as *mut T) }
     }
 
     /// Obtains a mutable reference to an item at an specified index.
     /// Does not check if the memory at the index is initialized.
+    ///
+    /// # Safety
+    /// `index` must be smaller than `len()`.
+    #[cfg(not(feature = "forbid-unsafe-api"))]
     pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
         unsafe { &mut *(self.items.as_ptr().add(index) as *mut T) }
     }
 
+    /// Swaps the items at indexes `a` and `b`.
+    /// returns `false` if either index is out of bounds.
+    pub fn swap(&mut self, a: usize, b: usize) -> bool {
+        if a >= self.pos || b >= self.pos {
+            return false;
+        }
+
+        self.get_init_slice_mut().swap(a, b);
+
+        true
+    }
+
+    /// Replaces the item at an specified index with `item`
+    /// and returns the previous one.
+    /// returns `None` if that index is out of bounds.
+    pub fn replace(&mut self, index: usize, item: T) -> Option<T> {
+        self.get_mut(index)
+            .map(|reference| std::mem::replace(reference, item))
+    }
+
+    /// Takes the item at an specified index out of the `FlowerPot`,
+    /// leaving `T::default()` in its place.
+    /// returns `None` if that index is out of bounds.
+    pub fn take(&mut self, index: usize) -> Option<T>
+    where
+        T: Default,
+    {
+        self.get_mut(index).map(std::mem::take)
+    }
+
+    /// Removes the item at an specified index and returns it,
+    /// the last item is moved into its place.
+    /// returns `None` if that index is out of bounds.
+    pub fn swap_remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.pos {
+            return None;
+        }
+
+        let last = self.pos - 1;
+        self.swap(index, last);
+
+        self.pop()
+    }
+
+    /// Returns a reference to the first item whose key extracted by `f` equals `key`.
+    /// returns `None` if there is no such item.
+    pub fn find_by_key<K: PartialEq, F: FnMut(&T) -> K>(&self, key: K, mut f: F) -> Option<&T> {
+        self.get_init_slice().iter().find(|item| f(item) == key)
+    }
+
+    /// Returns the index of the first item whose key extracted by `f` equals `key`.
+    /// returns `None` if there is no such item.
+    pub fn position_by_key<K: PartialEq, F: FnMut(&T) -> K>(
+        &self,
+        key: K,
+        mut f: F,
+    ) -> Option<usize> {
+        self.get_init_slice().iter().position(|item| f(item) == key)
+    }
+
+    /// Removes the first item whose key extracted by `f` equals `key` and returns it,
+    /// shifting every item after it to the left.
+    /// returns `None` if there is no such item.
+    pub fn remove_by_key<K: PartialEq, F: FnMut(&T) -> K>(&mut self, key: K, f: F) -> Option<T> {
+        let index = self.position_by_key(key, f)?;
+
+        self.remove(index)
+    }
+
+    /// Calls `f` on every item in order.
+    pub fn apply<F: FnMut(&mut T)>(&mut self, mut f: F) {
+        for item in self.get_init_slice_mut() {
+            f(item);
+        }
+    }
+
+    /// Calls `f` on every item in order along with its index.
+    pub fn apply_indexed<F: FnMut(usize, &mut T)>(&mut self, mut f: F) {
+        for (index, item) in self.get_init_slice_mut().iter_mut().enumerate() {
+            f(index, item);
+        }
+    }
+
+    /// Calls `f` on every item in order,
+    /// stopping at and returning the first `Err`.
+    /// items before the failing one stay modified.
+    pub fn try_apply<E, F>(&mut self, mut f: F) -> std::result::Result<(), E>
+    where
+        F: FnMut(&mut T) -> std::result::Result<(), E>,
+    {
+        for item in self.get_init_slice_mut() {
+            f(item)?;
+        }
+
+        Ok(())
+    }
+
+    /// Updates the metrics after the length grew.
+    #[inline]
+    fn record_len(&mut self) {
+        #[cfg(feature = "metrics")]
+        self.metrics.record_len(self.pos);
+
+        self.check_invariants();
+    }
+
+    /// Updates the metrics after a push was rejected.
+    #[inline]
+    fn record_rejected(&mut self) {
+        #[cfg(feature = "metrics")]
+        self.metrics.record_rejected();
+    }
+
     /// Obtains an immutable reference to the initialized part of the `FlowerPot`.
     /// if `pos` is `0` then returns a reference to an empty slice.
     pub fn get_init_slice(&self) -> &[T] {
@@ -153,6 +677,114 @@ impl<T, const N: usize> FlowerPot<T, N> {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+/// Length of a `FlowerPot` recorded by `FlowerPot::checkpoint`.
+pub struct Checkpoint {
+    len: usize,
+}
+
+impl Checkpoint {
+    /// Returns the length the `FlowerPot` had when this checkpoint was taken.
+    #[inline]
+    pub const fn position(&self) -> usize {
+        self.len
+    }
+}
+
+impl<T, const N: usize> Default for FlowerPot<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, I, const N: usize> std::ops::Index<I> for FlowerPot<T, N>
+where
+    I: SliceIndex<[T]>,
+{
+    type Output = I::Output;
+
+    /// Indexes into the initialized part of the `FlowerPot`,
+    /// panics like slices do if the index is out of bounds.
+    fn index(&self, index: I) -> &I::Output {
+        &self.get_init_slice()[index]
+    }
+}
+
+impl<T, I, const N: usize> std::ops::IndexMut<I> for FlowerPot<T, N>
+where
+    I: SliceIndex<[T]>,
+{
+    /// Mutably indexes into the initialized part of the `FlowerPot`,
+    /// panics like slices do if the index is out of bounds.
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+        &mut self.get_init_slice_mut()[index]
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for FlowerPot<T, N> {
+    fn as_ref(&self) -> &[T] {
+        self.get_init_slice()
+    }
+}
+
+impl<T, const N: usize> AsMut<[T]> for FlowerPot<T, N> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.get_init_slice_mut()
+    }
+}
+
+impl<T, const N: usize> Borrow<[T]> for FlowerPot<T, N> {
+    fn borrow(&self) -> &[T] {
+        self.get_init_slice()
+    }
+}
+
+impl<T, const N: usize> BorrowMut<[T]> for FlowerPot<T, N> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.get_init_slice_mut()
+    }
+}
+
+/// Error returned when items do not fit,
+/// outlined so the paths that succeed stay small.
+#[cold]
+#[inline(never)]
+pub(crate) fn storage_full() -> io::Error {
+    io::Error::from(io::ErrorKind::StorageFull)
+}
+
+/// Error returned when an argument is out of range,
+/// outlined so the paths that succeed stay small.
+#[cold]
+#[inline(never)]
+pub(crate) fn invalid_input() -> io::Error {
+    io::Error::from(io::ErrorKind::InvalidInput)
+}
+
+/// Error returned when the data read is malformed,
+/// outlined so the paths that succeed stay small.
+#[cold]
+#[inline(never)]
+pub(crate) fn invalid_data() -> io::Error {
+    io::Error::from(io::ErrorKind::InvalidData)
+}
+
+/// Error returned when the data read ends too early,
+/// outlined so the paths that succeed stay small.
+#[cold]
+#[inline(never)]
+pub(crate) fn unexpected_eof() -> io::Error {
+    io::Error::from(io::ErrorKind::UnexpectedEof)
+}
+
+/// Error returned when there is nothing to read yet,
+/// outlined so the paths that succeed stay small.
+#[cold]
+#[inline(never)]
+pub(crate) fn would_block() -> io::Error {
+    io::Error::from(io::ErrorKind::WouldBlock)
+}
+
 impl<T, const N: usize> std::ops::Drop for FlowerPot<T, N> {
     fn drop(&mut self) {
         if self.pos != 0 {
@@ -0,0 +1,129 @@
+use crate::FlowerPot;
+use crate::bytes::memchr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What a `LineBuffer` does with a line that does not fit in its buffer.
+pub enum Overlong {
+    /// Yields the full buffer as a line of its own
+    /// and continues the rest of the line as the next one.
+    Split,
+    /// Keeps the start of the line and drops the bytes
+    /// up to the line break, the start is yielded as the line.
+    Truncate,
+    /// Drops the whole line.
+    Discard,
+}
+
+/// Splits a stream of bytes into lines,
+/// buffering up to `N` bytes of a partial line across reads.
+/// lines end with `\n` or `\r\n`, the line break is not part of the yielded line.
+pub struct LineBuffer<const N: usize> {
+    buf: FlowerPot<u8, N>,
+    overlong: Overlong,
+    /// Length of the last returned line, dropped before the next operation.
+    pending: usize,
+    /// Bytes are dropped until the next line break.
+    dropping: bool,
+    /// The buffer holds a truncated line whose line break was dropped.
+    truncated: bool,
+}
+
+impl<const N: usize> LineBuffer<N> {
+    /// Creates a new empty `LineBuffer`.
+    pub fn new(overlong: Overlong) -> LineBuffer<N> {
+        const { assert!(N > 0, "`LineBuffer` must hold at least 1 byte") };
+
+        Self {
+            buf: FlowerPot::new(),
+            overlong,
+            pending: 0,
+            dropping: false,
+            truncated: false,
+        }
+    }
+
+    /// Buffers as many bytes of `bytes` as fit,
+    /// dropping the bytes of an overlong line.
+    /// returns the amount of bytes used up,
+    /// the rest should be fed again after taking out lines.
+    pub fn feed(&mut self, bytes: &[u8]) -> usize {
+        self.release();
+
+        let mut used = 0;
+
+        if self.dropping {
+            let Some(end) = memchr(b'\n', bytes) else {
+                return bytes.len();
+            };
+
+            used = end + 1;
+            self.dropping = false;
+            self.truncated = self.overlong == Overlong::Truncate;
+        }
+
+        if self.truncated {
+            return used;
+        }
+
+        used + self.buf.push_iter(bytes[used..].iter().copied())
+    }
+
+    /// Returns the next complete line.
+    /// returns `None` if more bytes are needed.
+    pub fn next_line(&mut self) -> Option<&[u8]> {
+        self.release();
+
+        let len = if self.truncated {
+            self.truncated = false;
+
+            self.buf.len()
+        } else if let Some(end) = self.buf.position(b'\n') {
+            end + 1
+        } else if self.buf.is_full() {
+            match self.overlong {
+                Overlong::Split => return self.split(),
+                Overlong::Truncate => self.dropping = true,
+                Overlong::Discard => {
+                    self.dropping = true;
+                    self.buf.clear();
+                }
+            }
+
+            return None;
+        } else {
+            return None;
+        };
+
+        self.pending = len;
+
+        let line = &self.buf.get_init_slice()[..len];
+        let line = line.strip_suffix(b"\n").unwrap_or(line);
+
+        Some(line.strip_suffix(b"\r").unwrap_or(line))
+    }
+
+    /// Obtains an immutable reference to the buffered bytes
+    /// that are not part of a returned line.
+    pub fn buffered(&self) -> &[u8] {
+        &self.buf.get_init_slice()[self.pending..]
+    }
+
+    /// Drops every buffered byte and the rest of an overlong line.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.pending = 0;
+        self.dropping = false;
+        self.truncated = false;
+    }
+
+    fn split(&mut self) -> Option<&[u8]> {
+        self.pending = N;
+
+        Some(self.buf.get_init_slice())
+    }
+
+    fn release(&mut self) {
+        self.buf.consume(self.pending);
+        self.pending = 0;
+    }
+}
@@ -0,0 +1,283 @@
+use crate::assert::wrap;
+use crate::{CapacityAssert, invalid_input};
+use std::io::Result;
+
+/// Size of the header in front of every record,
+/// its length as a little-endian `u16` followed by its timestamp as a little-endian `u64`.
+pub(crate) const HEADER: usize = 10;
+
+/// Tag at the start of every `FlowerLog`, followed by `VERSION`.
+pub(crate) const MAGIC: [u8; 8] = *b"FLWRPLOG";
+
+/// Version of the in-memory layout of `FlowerLog`.
+pub(crate) const VERSION: u32 = 1;
+
+/// Offset of the record bytes from the start of a `FlowerLog`.
+pub(crate) const BUF_OFFSET: usize = std::mem::offset_of!(FlowerLog<0>, buf);
+
+/// Ring of variable-length records stored in `BYTES` inline bytes,
+/// evicting the oldest whole records to make room for new ones.
+/// records are stamped through an optional clock hook.
+/// offsets wrap around with a mask if `BYTES` is a power of two,
+/// which `assert_capacity_power_of_two` can enforce.
+///
+/// The layout is stable so the log can be recovered from a memory dump
+/// through `dump::parse`, every field before `buf` is a little-endian `u32`
+/// following the magic number.
+#[repr(C)]
+pub struct FlowerLog<const BYTES: usize> {
+    magic: [u8; 8],
+    version: [u8; 4],
+    capacity: [u8; 4],
+    /// Offset of the oldest record.
+    head: [u8; 4],
+    /// Amount of bytes taken by records, including their headers.
+    used: [u8; 4],
+    /// Amount of records.
+    records: [u8; 4],
+    buf: [u8; BYTES],
+    clock: Option<fn() -> u64>,
+}
+
+impl<const BYTES: usize> FlowerLog<BYTES> {
+    /// Creates a new empty `FlowerLog` stamping every record with `0`.
+    pub const fn new() -> FlowerLog<BYTES> {
+        const {
+            assert!(
+                BYTES <= u32::MAX as usize,
+                "capacity does not fit the layout"
+            )
+        };
+
+        Self {
+            magic: MAGIC,
+            version: VERSION.to_le_bytes(),
+            capacity: (BYTES as u32).to_le_bytes(),
+            head: [0; 4],
+            used: [0; 4],
+            records: [0; 4],
+            buf: [0; BYTES],
+            clock: None,
+        }
+    }
+
+    /// Creates a new empty `FlowerLog` stamping every record
+    /// with the value returned by `clock` when it is pushed.
+    pub const fn with_clock(clock: fn() -> u64) -> FlowerLog<BYTES> {
+        let mut log = Self::new();
+        log.clock = Some(clock);
+
+        log
+    }
+
+    /// Fails to compile if `BYTES` is not a power of two.
+    #[inline]
+    pub const fn assert_capacity_power_of_two() {
+        CapacityAssert::<BYTES, 0>::POWER_OF_TWO
+    }
+
+    /// Returns the amount of records.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        u32::from_le_bytes(self.records) as usize
+    }
+
+    /// Returns `true` if there are no records.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the amount of bytes taken by records, including their headers.
+    #[inline]
+    pub const fn bytes_used(&self) -> usize {
+        u32::from_le_bytes(self.used) as usize
+    }
+
+    #[inline]
+    const fn head(&self) -> usize {
+        u32::from_le_bytes(self.head) as usize
+    }
+
+    fn set_state(&mut self, head: usize, used: usize, records: usize) {
+        self.head = (head as u32).to_le_bytes();
+        self.used = (used as u32).to_le_bytes();
+        self.records = (records as u32).to_le_bytes();
+    }
+
+    /// Appends `record` to the log, stamped by the clock hook.
+    /// evicts the oldest records until it fits,
+    /// returns `Err` with `InvalidInput` if it can never fit.
+    pub fn push(&mut self, record: &[u8]) -> Result<()> {
+        let timestamp = self.clock.map_or(0, |clock| clock());
+
+        self.push_with_timestamp(timestamp, record)
+    }
+
+    /// Appends `record` to the log, stamped with `timestamp`.
+    /// evicts the oldest records until it fits,
+    /// returns `Err` with `InvalidInput` if it can never fit.
+    pub fn push_with_timestamp(&mut self, timestamp: u64, record: &[u8]) -> Result<()> {
+        let Ok(len) = u16::try_from(record.len()) else {
+            let err = invalid_input();
+
+            return Err(err);
+        };
+
+        let size = HEADER + record.len();
+
+        if size > BYTES {
+            let err = invalid_input();
+
+            return Err(err);
+        }
+
+        while BYTES - self.bytes_used() < size {
+            self.evict();
+        }
+
+        let start = wrap::<BYTES>(self.head() + self.bytes_used());
+        self.write_at(start, &len.to_le_bytes());
+        self.write_at(wrap::<BYTES>(start + 2), &timestamp.to_le_bytes());
+        self.write_at(wrap::<BYTES>(start + HEADER), record);
+
+        self.set_state(self.head(), self.bytes_used() + size, self.len() + 1);
+
+        Ok(())
+    }
+
+    /// Drops the oldest record.
+    /// returns `false` if there are no records.
+    pub fn evict(&mut self) -> bool {
+        let Some(record) = self.iter().next() else {
+            return false;
+        };
+
+        let size = HEADER + record.len();
+
+        self.set_state(
+            wrap::<BYTES>(self.head() + size),
+            self.bytes_used() - size,
+            self.len() - 1,
+        );
+
+        true
+    }
+
+    /// Drops every record.
+    pub fn clear(&mut self) {
+        self.set_state(0, 0, 0);
+    }
+
+    /// Obtains the bytes of the log as laid out in memory,
+    /// which is what `dump::parse` expects.
+    pub fn dump_bytes(&self) -> &[u8] {
+        // SAFETY: every field up to and including `buf` is a byte array,
+        // so with `repr(C)` they are laid out right after each other without padding.
+        unsafe {
+            std::slice::from_raw_parts((self as *const Self).cast::<u8>(), BUF_OFFSET + BYTES)
+        }
+    }
+
+    /// Iterates over the records from the oldest to the newest.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = LogRecord<'_>> {
+        records(&self.buf, self.head(), self.len())
+    }
+
+    fn write_at(&mut self, offset: usize, bytes: &[u8]) {
+        let first = bytes.len().min(BYTES - offset);
+
+        self.buf[offset..offset + first].copy_from_slice(&bytes[..first]);
+        self.buf[..bytes.len() - first].copy_from_slice(&bytes[first..]);
+    }
+}
+
+/// Iterates over `count` records stored in the ring `buf`,
+/// starting with the one at `head`.
+/// the caller has to make sure the headers describe records within `buf`.
+pub(crate) fn records(
+    buf: &[u8],
+    head: usize,
+    count: usize,
+) -> impl ExactSizeIterator<Item = LogRecord<'_>> {
+    let mut offset = head;
+
+    (0..count).map(move |_| {
+        let record = record_at(buf, offset);
+        offset = (offset + HEADER + record.len()) % buf.len();
+
+        record
+    })
+}
+
+/// Reads the header of the record at `offset` in the ring `buf`,
+/// returning the record with its length clamped to `buf`.
+pub(crate) fn record_at(buf: &[u8], offset: usize) -> LogRecord<'_> {
+    let mut header = [0; HEADER];
+
+    for (index, byte) in header.iter_mut().enumerate() {
+        *byte = buf[(offset + index) % buf.len()];
+    }
+
+    let [len @ .., _, _, _, _, _, _, _, _] = header;
+    let [_, _, timestamp @ ..] = header;
+
+    let len = u16::from_le_bytes(len) as usize;
+    let timestamp = u64::from_le_bytes(timestamp);
+
+    let start = (offset + HEADER) % buf.len();
+    let len = len.min(buf.len());
+    let first = len.min(buf.len() - start);
+
+    LogRecord {
+        timestamp,
+        first: &buf[start..start + first],
+        second: &buf[..len - first],
+    }
+}
+
+impl<const BYTES: usize> Default for FlowerLog<BYTES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Record borrowed from a `FlowerLog`,
+/// which may be split in two where the ring wraps around.
+pub struct LogRecord<'a> {
+    timestamp: u64,
+    first: &'a [u8],
+    second: &'a [u8],
+}
+
+impl<'a> LogRecord<'a> {
+    /// Returns the timestamp the record was stamped with.
+    #[inline]
+    pub const fn timestamp(&self) -> u64 {
+        self.timestamp
+    }
+
+    /// Returns the length of the record in bytes.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+
+    /// Returns `true` if the record holds no bytes.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Obtains the two parts of the record,
+    /// the second one is empty unless the record wraps around.
+    pub fn as_slices(&self) -> (&'a [u8], &'a [u8]) {
+        (self.first, self.second)
+    }
+
+    /// Iterates over the bytes of the record.
+    pub fn bytes(&self) -> impl Iterator<Item = u8> + 'a {
+        self.first.iter().chain(self.second).copied()
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{FlowerPot, storage_full};
+use std::borrow::Borrow;
+use std::io::Result;
+
+/// Map stored in a `FlowerPot` of key-value pairs,
+/// can store up to `N` entries.
+/// lookups scan the entries linearly, which is fast for small `N`.
+#[derive(Debug, Default)]
+pub struct FlowerMap<K, V, const N: usize> {
+    entries: FlowerPot<(K, V), N>,
+}
+
+impl<K: Eq, V, const N: usize> FlowerMap<K, V, N> {
+    /// Creates a new empty `FlowerMap`.
+    pub fn new() -> FlowerMap<K, V, N> {
+        Self {
+            entries: FlowerPot::new(),
+        }
+    }
+
+    /// Returns the amount of entries in the map.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map holds no entries.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if the map can not hold any more entries.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.entries.is_full()
+    }
+
+    /// Returns the total amount of entries the map can hold,
+    /// which is always `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    fn index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.entries
+            .get_init_slice()
+            .iter()
+            .position(|(k, _)| k.borrow() == key)
+    }
+
+    /// Returns `true` if the map holds an entry for `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.index_of(key).is_some()
+    }
+
+    /// Obtains an immutable reference to the value of `key`.
+    /// returns `None` if the map holds no such entry.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.index_of(key)?;
+
+        self.entries.get(index).map(|(_, v)| v)
+    }
+
+    /// Obtains a mutable reference to the value of `key`.
+    /// returns `None` if the map holds no such entry.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.index_of(key)?;
+
+        self.entries.get_mut(index).map(|(_, v)| v)
+    }
+
+    /// Inserts a value for `key`, returning the previous value if there was one.
+    /// returns `Err` with `StorageFull` if `key` is new and the map is full.
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>> {
+        match self.entry(key) {
+            Entry::Occupied(mut entry) => Ok(Some(entry.insert(value))),
+            Entry::Vacant(entry) => entry.insert(value).map(|_| None),
+        }
+    }
+
+    /// Removes the entry of `key` and returns its value.
+    /// the last entry is moved into its place.
+    /// returns `None` if the map holds no such entry.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.index_of(key)?;
+
+        self.entries.swap_remove(index).map(|(_, v)| v)
+    }
+
+    /// Obtains the entry of `key` for in-place manipulation.
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, N> {
+        match self.index_of(&key) {
+            Some(index) => Entry::Occupied(OccupiedEntry {
+                entries: &mut self.entries,
+                index,
+            }),
+            None => Entry::Vacant(VacantEntry {
+                entries: &mut self.entries,
+                key,
+            }),
+        }
+    }
+
+    /// Drops every entry in the map.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns an iterator over the entries in the map.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.entries.get_init_slice().iter().map(|(k, v)| (k, v))
+    }
+
+    /// Returns an iterator over the entries in the map,
+    /// with mutable references to the values.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        self.entries
+            .get_init_slice_mut()
+            .iter_mut()
+            .map(|(k, v)| (&*k, v))
+    }
+
+    /// Returns an iterator over the keys in the map.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over the values in the map.
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+}
+
+/// Entry of a `FlowerMap` obtained from `FlowerMap::entry`.
+pub enum Entry<'a, K, V, const N: usize> {
+    /// The map holds a value for the key.
+    Occupied(OccupiedEntry<'a, K, V, N>),
+    /// The map holds no value for the key.
+    Vacant(VacantEntry<'a, K, V, N>),
+}
+
+impl<'a, K, V, const N: usize> Entry<'a, K, V, N> {
+    /// Obtains a mutable reference to the value,
+    /// inserting `default` if the entry is vacant.
+    /// returns `Err` with `StorageFull` if the entry is vacant and the map is full.
+    pub fn or_insert(self, default: V) -> Result<&'a mut V> {
+        self.or_insert_with(|| default)
+    }
+
+    /// Obtains a mutable reference to the value,
+    /// inserting the result of `default` if the entry is vacant.
+    /// returns `Err` with `StorageFull` if the entry is vacant and the map is full,
+    /// in which case `default` is not called.
+    pub fn or_insert_with<F: FnOnce() -> V>(self, default: F) -> Result<&'a mut V> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_mut()),
+            Entry::Vacant(entry) => {
+                if entry.entries.is_full() {
+                    let err = storage_full();
+
+                    return Err(err);
+                }
+
+                entry.insert(default())
+            }
+        }
+    }
+
+    /// Obtains a mutable reference to the value,
+    /// inserting `V::default()` if the entry is vacant.
+    /// returns `Err` with `StorageFull` if the entry is vacant and the map is full.
+    pub fn or_default(self) -> Result<&'a mut V>
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    /// Calls `f` with the value if the entry is occupied.
+    pub fn and_modify<F: FnOnce(&mut V)>(mut self, f: F) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+
+        self
+    }
+
+    /// Obtains an immutable reference to the key of the entry.
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+}
+
+/// Occupied entry of a `FlowerMap`.
+pub struct OccupiedEntry<'a, K, V, const N: usize> {
+    entries: &'a mut FlowerPot<(K, V), N>,
+    index: usize,
+}
+
+impl<'a, K, V, const N: usize> OccupiedEntry<'a, K, V, N> {
+    /// Obtains an immutable reference to the key of the entry.
+    pub fn key(&self) -> &K {
+        &self.entries[self.index].0
+    }
+
+    /// Obtains an immutable reference to the value of the entry.
+    pub fn get(&self) -> &V {
+        &self.entries[self.index].1
+    }
+
+    /// Obtains a mutable reference to the value of the entry.
+    pub fn get_mut(&mut self) -> &mut V {
+        &mut self.entries[self.index].1
+    }
+
+    /// Converts the entry into a mutable reference to its value.
+    pub fn into_mut(self) -> &'a mut V {
+        &mut self.entries[self.index].1
+    }
+
+    /// Replaces the value of the entry and returns the previous one.
+    pub fn insert(&mut self, value: V) -> V {
+        std::mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the map and returns its value.
+    pub fn remove(self) -> V {
+        // `index` always points at an existing entry.
+        self.entries.swap_remove(self.index).unwrap().1
+    }
+}
+
+/// Vacant entry of a `FlowerMap`.
+pub struct VacantEntry<'a, K, V, const N: usize> {
+    entries: &'a mut FlowerPot<(K, V), N>,
+    key: K,
+}
+
+impl<'a, K, V, const N: usize> VacantEntry<'a, K, V, N> {
+    /// Obtains an immutable reference to the key of the entry.
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    /// Inserts a value for the key and returns a mutable reference to it.
+    /// returns `Err` with `StorageFull` if the map is full.
+    pub fn insert(self, value: V) -> Result<&'a mut V> {
+        self.entries.push((self.key, value))?;
+
+        let last = self.entries.len() - 1;
+
+        Ok(&mut self.entries[last].1)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{CapacityError, FlowerPot};
+
+impl<T: Ord, const N: usize> FlowerPot<T, N> {
+    /// Merges the sorted items of `other` into the sorted items of `self`,
+    /// keeping them sorted. equal items of `self` come before the ones of `other`.
+    /// returns `Err` with `other` if the items do not fit, in which case nothing is merged.
+    pub fn merge_sorted(
+        &mut self,
+        mut other: FlowerPot<T, N>,
+    ) -> Result<(), CapacityError<FlowerPot<T, N>>> {
+        if other.len() > self.remaining_capacity() {
+            self.record_rejected();
+
+            return Err(CapacityError::new(other));
+        }
+
+        let mut left = self.pos;
+        let mut slot = self.pos + other.len();
+        let total = slot;
+
+        // Items are moved around while merging,
+        // leaking them on a panic in `Ord` is preferable to dropping them twice.
+        self.pos = 0;
+
+        let items = self.items.as_mut_ptr();
+
+        while let Some(last) = other.get_init_slice().last() {
+            slot -= 1;
+
+            // SAFETY: `left - 1` is an item of `self` that was not moved yet.
+            if left > 0 && unsafe { (*items.add(left - 1)).assume_init_ref() } > last {
+                left -= 1;
+
+                // SAFETY: `slot` is past `left`, so it is either unused
+                // or an item of `self` that was already moved.
+                unsafe { items.add(slot).write(items.add(left).read()) };
+            } else {
+                // SAFETY: same as above, and there is an item to pop.
+                unsafe { (*items.add(slot)).write(other.pop().unwrap_unchecked()) };
+            }
+        }
+
+        self.pos = total;
+        self.record_len();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use crate::FlowerPot;
+
+/// Usage statistics of a `FlowerPot`.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Metrics {
+    high_water: usize,
+    rejected: usize,
+}
+
+impl Metrics {
+    pub(crate) const fn new() -> Metrics {
+        Self {
+            high_water: 0,
+            rejected: 0,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn record_len(&mut self, len: usize) {
+        self.high_water = self.high_water.max(len);
+    }
+
+    #[inline]
+    pub(crate) fn record_rejected(&mut self) {
+        self.rejected = self.rejected.saturating_add(1);
+    }
+}
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Returns the biggest length the `FlowerPot` reached
+    /// since it was created or the metrics were reset.
+    #[inline]
+    pub fn high_water_mark(&self) -> usize {
+        self.metrics.high_water
+    }
+
+    /// Returns the amount of pushes rejected because the `FlowerPot` was full
+    /// since it was created or the metrics were reset.
+    #[inline]
+    pub fn rejected_pushes(&self) -> usize {
+        self.metrics.rejected
+    }
+
+    /// Resets the high water mark to the current length.
+    pub fn reset_high_water_mark(&mut self) {
+        self.metrics.high_water = self.pos;
+        self.check_invariants();
+    }
+
+    /// Resets the amount of rejected pushes to 0.
+    pub fn reset_rejected_pushes(&mut self) {
+        self.metrics.rejected = 0;
+    }
+}
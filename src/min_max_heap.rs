@@ -0,0 +1,220 @@
+use crate::FlowerPot;
+use std::io::Result;
+
+/// Double-ended priority queue stored in a `FlowerPot`,
+/// can store up to `N` items.
+/// both the smallest and the biggest item can be popped in `O(log n)`.
+#[derive(Debug, Default)]
+pub struct FlowerMinMaxHeap<T, const N: usize> {
+    items: FlowerPot<T, N>,
+}
+
+/// Returns `true` if `index` lies on a level ordered by minimum,
+/// the root level is `0` and levels alternate starting with minimum.
+fn is_min_level(index: usize) -> bool {
+    (index + 1).ilog2().is_multiple_of(2)
+}
+
+impl<T: Ord, const N: usize> FlowerMinMaxHeap<T, N> {
+    /// Creates a new empty `FlowerMinMaxHeap`.
+    pub fn new() -> FlowerMinMaxHeap<T, N> {
+        Self {
+            items: FlowerPot::new(),
+        }
+    }
+
+    /// Returns the amount of items in the heap.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the heap holds no items.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Returns `true` if the heap can not hold any more items.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.items.is_full()
+    }
+
+    /// Returns the total amount of items the heap can hold,
+    /// which is always `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Pushes an item to the heap.
+    /// returns `Err` if the heap is full.
+    pub fn push(&mut self, item: T) -> Result<()> {
+        self.items.push(item)?;
+        self.bubble_up(self.items.len() - 1);
+
+        Ok(())
+    }
+
+    /// Obtains an immutable reference to the smallest item.
+    /// returns `None` if the heap is empty.
+    pub fn peek_min(&self) -> Option<&T> {
+        self.items.get(0)
+    }
+
+    /// Obtains an immutable reference to the biggest item.
+    /// returns `None` if the heap is empty.
+    pub fn peek_max(&self) -> Option<&T> {
+        self.items.get(self.max_index()?)
+    }
+
+    /// Pops the smallest item from the heap.
+    /// returns `None` if the heap is empty.
+    pub fn pop_min(&mut self) -> Option<T> {
+        self.pop_at(0)
+    }
+
+    /// Pops the biggest item from the heap.
+    /// returns `None` if the heap is empty.
+    pub fn pop_max(&mut self) -> Option<T> {
+        self.pop_at(self.max_index()?)
+    }
+
+    /// Drops every item in the heap.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Obtains an immutable reference to the items in heap order.
+    pub fn as_slice(&self) -> &[T] {
+        self.items.get_init_slice()
+    }
+
+    /// Consumes the heap and returns its items sorted in ascending order.
+    pub fn into_sorted(mut self) -> FlowerPot<T, N> {
+        let mut sorted = FlowerPot::new();
+
+        while let Some(item) = self.pop_min() {
+            // Both pots share the capacity `N`.
+            let _ = sorted.push(item);
+        }
+
+        sorted
+    }
+
+    fn max_index(&self) -> Option<usize> {
+        let items = self.items.get_init_slice();
+
+        match items.len() {
+            0 => None,
+            1 => Some(0),
+            2 => Some(1),
+            _ if items[1] >= items[2] => Some(1),
+            _ => Some(2),
+        }
+    }
+
+    fn pop_at(&mut self, index: usize) -> Option<T> {
+        let last = self.items.len().checked_sub(1)?;
+        self.items.swap(index, last);
+
+        let item = self.items.pop();
+
+        if index < self.items.len() {
+            self.trickle_down(index);
+        }
+
+        item
+    }
+
+    fn bubble_up(&mut self, index: usize) {
+        if index == 0 {
+            return;
+        }
+
+        let items = self.items.get_init_slice_mut();
+        let parent = (index - 1) / 2;
+
+        if is_min_level(index) {
+            if items[index] > items[parent] {
+                items.swap(index, parent);
+                bubble_up_by(items, parent, |a, b| a > b);
+            } else {
+                bubble_up_by(items, index, |a, b| a < b);
+            }
+        } else if items[index] < items[parent] {
+            items.swap(index, parent);
+            bubble_up_by(items, parent, |a, b| a < b);
+        } else {
+            bubble_up_by(items, index, |a, b| a > b);
+        }
+    }
+
+    fn trickle_down(&mut self, index: usize) {
+        let items = self.items.get_init_slice_mut();
+
+        if is_min_level(index) {
+            trickle_down_by(items, index, |a, b| a < b);
+        } else {
+            trickle_down_by(items, index, |a, b| a > b);
+        }
+    }
+}
+
+/// Moves the item at `index` up through its grandparents
+/// while it comes before them according to `before`.
+fn bubble_up_by<T>(items: &mut [T], mut index: usize, before: impl Fn(&T, &T) -> bool) {
+    while index >= 3 {
+        let grandparent = ((index - 1) / 2 - 1) / 2;
+
+        if !before(&items[index], &items[grandparent]) {
+            break;
+        }
+
+        items.swap(index, grandparent);
+        index = grandparent;
+    }
+}
+
+/// Moves the item at `index` down through its children and grandchildren
+/// while any of them comes before it according to `before`.
+fn trickle_down_by<T>(items: &mut [T], mut index: usize, before: impl Fn(&T, &T) -> bool) {
+    loop {
+        let first_child = 2 * index + 1;
+        let first_grandchild = 2 * first_child + 1;
+
+        let candidates = (first_child..first_child + 2)
+            .chain(first_grandchild..first_grandchild + 4)
+            .filter(|&candidate| candidate < items.len());
+
+        let Some(next) = candidates.reduce(|best, candidate| {
+            if before(&items[candidate], &items[best]) {
+                candidate
+            } else {
+                best
+            }
+        }) else {
+            return;
+        };
+
+        if !before(&items[next], &items[index]) {
+            return;
+        }
+
+        items.swap(next, index);
+
+        if next < first_grandchild {
+            // `next` is a child, which is a leaf on the other kind of level.
+            return;
+        }
+
+        let parent = (next - 1) / 2;
+
+        if before(&items[parent], &items[next]) {
+            items.swap(next, parent);
+        }
+
+        index = next;
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{FlowerMap, FlowerPot, storage_full};
+use std::borrow::Borrow;
+use std::io::Result;
+
+/// Map from keys to inline groups of values,
+/// can store up to `KN` keys with up to `VN` values each.
+#[derive(Debug, Default)]
+pub struct FlowerMultiMap<K, V, const KN: usize, const VN: usize> {
+    groups: FlowerMap<K, FlowerPot<V, VN>, KN>,
+}
+
+impl<K: Eq, V, const KN: usize, const VN: usize> FlowerMultiMap<K, V, KN, VN> {
+    /// Creates a new empty `FlowerMultiMap`.
+    pub fn new() -> FlowerMultiMap<K, V, KN, VN> {
+        Self {
+            groups: FlowerMap::new(),
+        }
+    }
+
+    /// Returns the amount of keys in the map.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns `true` if the map holds no keys.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Returns `true` if the map holds values for `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.groups.contains_key(key)
+    }
+
+    /// Adds a value to the group of `key`.
+    /// returns `Err` with `StorageFull` if the group is full
+    /// or if `key` is new and the map is full.
+    pub fn insert(&mut self, key: K, value: V) -> Result<()> {
+        if VN == 0 {
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        self.groups.entry(key).or_default()?.push(value)
+    }
+
+    /// Returns an iterator over the values of `key`,
+    /// which is empty if the map holds no such key.
+    pub fn get_all<Q>(&self, key: &Q) -> std::slice::Iter<'_, V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.get_slice(key).iter()
+    }
+
+    /// Obtains the values of `key` as a slice,
+    /// which is empty if the map holds no such key.
+    pub fn get_slice<Q>(&self, key: &Q) -> &[V]
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        match self.groups.get(key) {
+            Some(group) => group.get_init_slice(),
+            None => &[],
+        }
+    }
+
+    /// Removes a single value from the group of `key`,
+    /// the key itself is removed along with its last value.
+    /// returns `false` if no such value was found.
+    pub fn remove<Q>(&mut self, key: &Q, value: &V) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+        V: PartialEq,
+    {
+        let Some(group) = self.groups.get_mut(key) else {
+            return false;
+        };
+
+        let Some(index) = group.get_init_slice().iter().position(|v| v == value) else {
+            return false;
+        };
+
+        group.remove(index);
+
+        if group.is_empty() {
+            self.groups.remove(key);
+        }
+
+        true
+    }
+
+    /// Removes `key` and returns its group of values.
+    /// returns `None` if the map holds no such key.
+    pub fn remove_all<Q>(&mut self, key: &Q) -> Option<FlowerPot<V, VN>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.groups.remove(key)
+    }
+
+    /// Drops every key and value in the map.
+    pub fn clear(&mut self) {
+        self.groups.clear();
+    }
+
+    /// Returns an iterator over the keys in the map.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.groups.keys()
+    }
+
+    /// Returns an iterator over every key with its values.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &[V])> {
+        self.groups
+            .iter()
+            .map(|(k, group)| (k, group.get_init_slice()))
+    }
+}
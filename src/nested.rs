@@ -0,0 +1,94 @@
+use crate::FlowerPot;
+
+impl<T, const M: usize, const N: usize> FlowerPot<FlowerPot<T, M>, N> {
+    /// Moves the items of every inner `FlowerPot`, in order, into a single one.
+    /// fails to compile if `K` is smaller than `M * N`,
+    /// since computing the capacity in the signature is not possible on stable.
+    pub fn flatten<const K: usize>(mut self) -> FlowerPot<T, K> {
+        const { assert!(K >= M * N, "capacity is smaller than required") };
+
+        let mut flat = FlowerPot::new();
+
+        while let Some(mut inner) = self.pop() {
+            while let Some(item) = inner.pop() {
+                // Can not fail, `K` is big enough for every item of every inner pot.
+                let _ = flat.push(item);
+            }
+        }
+
+        flat.get_init_slice_mut().reverse();
+        flat
+    }
+}
+
+impl<T, const M: usize, const N: usize> FlowerPot<FlowerPot<T, M>, N> {
+    /// Iterates over the items of every inner `FlowerPot` in order.
+    pub fn iter_flat(&self) -> impl Iterator<Item = &T> {
+        self.get_init_slice().iter_flat()
+    }
+
+    /// Returns the amount of items in every inner `FlowerPot` combined.
+    pub fn total_len(&self) -> usize {
+        self.get_init_slice().total_len()
+    }
+}
+
+/// Segmented buffers made of several pots,
+/// which can be consumed as a single stream of items.
+pub trait FlatPots<T> {
+    /// Iterates over the items of every pot in order.
+    fn iter_flat<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+
+    /// Returns the amount of items in every pot combined.
+    fn total_len(&self) -> usize;
+}
+
+impl<T, const M: usize> FlatPots<T> for [FlowerPot<T, M>] {
+    fn iter_flat<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.iter().flat_map(FlowerPot::get_init_slice)
+    }
+
+    fn total_len(&self) -> usize {
+        self.iter().map(FlowerPot::len).sum()
+    }
+}
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Splits the items into runs of consecutive items
+    /// for which `same` returns `true`, keeping their order.
+    pub fn group_by<F>(mut self, mut same: F) -> FlowerPot<FlowerPot<T, N>, N>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let mut groups = FlowerPot::<FlowerPot<T, N>, N>::new();
+
+        while let Some(item) = self.pop() {
+            let starts_group = match groups.get_init_slice().last() {
+                Some(group) => !same(&item, &group[group.len() - 1]),
+                None => true,
+            };
+
+            if starts_group {
+                // Can not fail, there are never more groups than items.
+                let _ = groups.push(FlowerPot::new());
+            }
+
+            let group = groups.len() - 1;
+
+            // Can not fail, a group never holds more than every item.
+            let _ = groups[group].push(item);
+        }
+
+        for group in groups.get_init_slice_mut() {
+            group.get_init_slice_mut().reverse();
+        }
+
+        groups.get_init_slice_mut().reverse();
+        groups
+    }
+}
@@ -0,0 +1,127 @@
+use crate::FlowerPot;
+use std::fmt;
+use std::mem::ManuallyDrop;
+
+/// Length marking an `OptionPot` that holds no `FlowerPot`.
+const NONE: usize = usize::MAX;
+
+/// Optional `FlowerPot` that takes no more space than the `FlowerPot` itself.
+/// `Option<FlowerPot<T, N>>` needs an extra tag, since the length has no invalid values
+/// the compiler could use, while this stores an impossible length to mark `None`.
+pub struct OptionPot<T, const N: usize> {
+    pot: ManuallyDrop<FlowerPot<T, N>>,
+}
+
+impl<T, const N: usize> OptionPot<T, N> {
+    /// Creates a new `OptionPot` holding no `FlowerPot`.
+    pub fn none() -> OptionPot<T, N> {
+        const { assert!(N != NONE, "capacity collides with the `None` marker") };
+
+        let mut pot = FlowerPot::new();
+        pot.pos = NONE;
+
+        Self {
+            pot: ManuallyDrop::new(pot),
+        }
+    }
+
+    /// Creates a new `OptionPot` holding `pot`.
+    pub fn some(pot: FlowerPot<T, N>) -> OptionPot<T, N> {
+        Self {
+            pot: ManuallyDrop::new(pot),
+        }
+    }
+
+    /// Returns `true` if a `FlowerPot` is held.
+    #[inline]
+    pub fn is_some(&self) -> bool {
+        self.pot.pos != NONE
+    }
+
+    /// Returns `true` if no `FlowerPot` is held.
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.pot.pos == NONE
+    }
+
+    /// Obtains an immutable reference to the held `FlowerPot`.
+    /// returns `None` if there is none.
+    pub fn as_ref(&self) -> Option<&FlowerPot<T, N>> {
+        self.is_some().then_some(&*self.pot)
+    }
+
+    /// Obtains a mutable reference to the held `FlowerPot`.
+    /// returns `None` if there is none.
+    pub fn as_mut(&mut self) -> Option<&mut FlowerPot<T, N>> {
+        self.is_some().then_some(&mut *self.pot)
+    }
+
+    /// Takes the held `FlowerPot` out, leaving none in its place.
+    pub fn take(&mut self) -> Option<FlowerPot<T, N>> {
+        std::mem::take(self).into_option()
+    }
+
+    /// Stores `pot` and returns the `FlowerPot` held before.
+    pub fn replace(&mut self, pot: FlowerPot<T, N>) -> Option<FlowerPot<T, N>> {
+        std::mem::replace(self, Self::some(pot)).into_option()
+    }
+
+    /// Obtains a mutable reference to the held `FlowerPot`,
+    /// storing the one returned by `f` first if there is none.
+    pub fn get_or_insert_with<F: FnOnce() -> FlowerPot<T, N>>(
+        &mut self,
+        f: F,
+    ) -> &mut FlowerPot<T, N> {
+        if self.is_none() {
+            *self = Self::some(f());
+        }
+
+        &mut self.pot
+    }
+
+    /// Converts into an `Option`.
+    pub fn into_option(self) -> Option<FlowerPot<T, N>> {
+        let mut this = ManuallyDrop::new(self);
+
+        if this.is_none() {
+            return None;
+        }
+
+        // SAFETY: `this` is never used or dropped again,
+        // so the `FlowerPot` is moved out exactly once.
+        Some(unsafe { ManuallyDrop::take(&mut this.pot) })
+    }
+}
+
+impl<T, const N: usize> Default for OptionPot<T, N> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<T, const N: usize> From<Option<FlowerPot<T, N>>> for OptionPot<T, N> {
+    fn from(pot: Option<FlowerPot<T, N>>) -> Self {
+        pot.map_or_else(Self::none, Self::some)
+    }
+}
+
+impl<T, const N: usize> From<OptionPot<T, N>> for Option<FlowerPot<T, N>> {
+    fn from(pot: OptionPot<T, N>) -> Self {
+        pot.into_option()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for OptionPot<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.as_ref(), f)
+    }
+}
+
+impl<T, const N: usize> Drop for OptionPot<T, N> {
+    fn drop(&mut self) {
+        if self.is_some() {
+            // SAFETY: the `FlowerPot` holds a valid length and is never used again.
+            unsafe { ManuallyDrop::drop(&mut self.pot) }
+        }
+    }
+}
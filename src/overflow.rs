@@ -0,0 +1,78 @@
+use crate::FlowerPot;
+use std::ops::Deref;
+
+/// `FlowerPot` that hands pushes beyond its capacity to a callback
+/// instead of failing.
+/// the callback receives the full pot along with the rejected item,
+/// so it can spill the buffered items, push the item itself or drop it.
+pub struct FlowerPotWithOverflow<T, const N: usize, F>
+where
+    F: FnMut(&mut FlowerPot<T, N>, T),
+{
+    pot: FlowerPot<T, N>,
+    overflow: F,
+}
+
+impl<T, const N: usize, F> FlowerPotWithOverflow<T, N, F>
+where
+    F: FnMut(&mut FlowerPot<T, N>, T),
+{
+    /// Creates a new empty `FlowerPotWithOverflow` using `overflow` as the callback.
+    pub fn new(overflow: F) -> FlowerPotWithOverflow<T, N, F> {
+        Self {
+            pot: FlowerPot::new(),
+            overflow,
+        }
+    }
+
+    /// Pushes an item to the pot,
+    /// calls the overflow callback if the pot is full.
+    pub fn push(&mut self, item: T) {
+        if self.pot.is_full() {
+            (self.overflow)(&mut self.pot, item);
+        } else {
+            // The pot is not full, so this can not fail.
+            let _ = self.pot.push(item);
+        }
+    }
+
+    /// Pops an item from the pot.
+    /// returns `None` if the pot is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.pot.pop()
+    }
+
+    /// Obtains a mutable reference to the underlying `FlowerPot`.
+    pub fn pot_mut(&mut self) -> &mut FlowerPot<T, N> {
+        &mut self.pot
+    }
+
+    /// Returns the underlying `FlowerPot`, dropping the callback.
+    pub fn into_inner(self) -> FlowerPot<T, N> {
+        self.pot
+    }
+}
+
+impl<T, const N: usize, F> Deref for FlowerPotWithOverflow<T, N, F>
+where
+    F: FnMut(&mut FlowerPot<T, N>, T),
+{
+    type Target = FlowerPot<T, N>;
+
+    fn deref(&self) -> &FlowerPot<T, N> {
+        &self.pot
+    }
+}
+
+impl<T, const N: usize, F> Extend<T> for FlowerPotWithOverflow<T, N, F>
+where
+    F: FnMut(&mut FlowerPot<T, N>, T),
+{
+    /// Pushes every item of `iter`,
+    /// calling the overflow callback whenever the pot is full.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
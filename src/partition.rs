@@ -0,0 +1,49 @@
+use crate::FlowerPot;
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Moves every item for which `predicate` returns `true` into the first pot
+    /// and every other item into the second one, keeping their order.
+    pub fn partition<F>(mut self, mut predicate: F) -> (FlowerPot<T, N>, FlowerPot<T, N>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut left = FlowerPot::new();
+        let mut right = FlowerPot::new();
+
+        while let Some(item) = self.pop() {
+            let side = if predicate(&item) {
+                &mut left
+            } else {
+                &mut right
+            };
+
+            // Can not fail, neither side holds more than every item.
+            let _ = side.push(item);
+        }
+
+        left.get_init_slice_mut().reverse();
+        right.get_init_slice_mut().reverse();
+
+        (left, right)
+    }
+}
+
+impl<A, B, const N: usize> FlowerPot<(A, B), N> {
+    /// Splits the pairs into a pot of their first
+    /// and a pot of their second elements, keeping their order.
+    pub fn unzip(mut self) -> (FlowerPot<A, N>, FlowerPot<B, N>) {
+        let mut left = FlowerPot::new();
+        let mut right = FlowerPot::new();
+
+        while let Some((a, b)) = self.pop() {
+            // Can not fail, both sides hold as many items as there are pairs.
+            let _ = left.push(a);
+            let _ = right.push(b);
+        }
+
+        left.get_init_slice_mut().reverse();
+        right.get_init_slice_mut().reverse();
+
+        (left, right)
+    }
+}
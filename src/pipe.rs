@@ -0,0 +1,239 @@
+use crate::{invalid_input, storage_full, would_block};
+use std::cell::UnsafeCell;
+use std::cmp::min;
+use std::io::Result;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Single-producer single-consumer byte pipe
+/// holding up to `N` bytes of inline storage.
+/// Data is written and read through contiguous grants,
+/// which makes it suitable for DMA style double-buffering.
+pub struct FlowerPipe<const N: usize> {
+    buf: UnsafeCell<[u8; N]>,
+    /// Position where the next committed write starts.
+    write: AtomicUsize,
+    /// Position where the next read starts.
+    read: AtomicUsize,
+    /// End of readable data when the pipe has wrapped around.
+    last: AtomicUsize,
+    /// End of the currently granted write region.
+    reserve: AtomicUsize,
+}
+
+// SAFETY: The producer and consumer halves only ever touch
+// disjoint regions of `buf`, coordinated through the atomic indices.
+unsafe impl<const N: usize> Sync for FlowerPipe<N> {}
+
+impl<const N: usize> FlowerPipe<N> {
+    /// Creates a new empty `FlowerPipe`.
+    pub const fn new() -> FlowerPipe<N> {
+        Self {
+            buf: UnsafeCell::new([0; N]),
+            write: AtomicUsize::new(0),
+            read: AtomicUsize::new(0),
+            last: AtomicUsize::new(0),
+            reserve: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the total amount of bytes the pipe can hold.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Splits the pipe into its producer and consumer halves.
+    /// both halves borrow the pipe, so they can be moved
+    /// into scoped threads or interrupt handlers.
+    pub fn split(&mut self) -> (PipeProducer<'_, N>, PipeConsumer<'_, N>) {
+        let pipe = &*self;
+
+        (PipeProducer { pipe }, PipeConsumer { pipe })
+    }
+
+    /// # Safety
+    /// `start + len` must not exceed `N` and the region
+    /// must not be handed out to the other half at the same time.
+    #[allow(clippy::mut_from_ref)]
+    unsafe fn slice_mut(&self, start: usize, len: usize) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.buf.get().cast::<u8>().add(start), len) }
+    }
+}
+
+impl<const N: usize> Default for FlowerPipe<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writing half of a `FlowerPipe`.
+pub struct PipeProducer<'a, const N: usize> {
+    pipe: &'a FlowerPipe<N>,
+}
+
+impl<'a, const N: usize> PipeProducer<'a, N> {
+    /// Requests a contiguous writable region of exactly `len` bytes.
+    /// returns `Err` with `StorageFull` if there is no contiguous space available
+    /// and `InvalidInput` if `len` is bigger than the capacity of the pipe.
+    pub fn grant(&mut self, len: usize) -> Result<WriteGrant<'_, N>> {
+        if len > N {
+            return Err(invalid_input());
+        }
+
+        let pipe = self.pipe;
+        let write = pipe.write.load(Ordering::Acquire);
+        let read = pipe.read.load(Ordering::Acquire);
+
+        let start = if write < read {
+            // The pipe is already inverted, we can only write up to `read`.
+            if write + len < read {
+                write
+            } else {
+                return Err(storage_full());
+            }
+        } else if write + len <= N {
+            write
+        } else if len < read {
+            // Not enough space at the end, wrap around to the start.
+            0
+        } else {
+            return Err(storage_full());
+        };
+
+        pipe.reserve.store(start + len, Ordering::Release);
+
+        // SAFETY: the region lies between `write` and `read`,
+        // which the consumer will not touch until it is committed.
+        let buf = unsafe { pipe.slice_mut(start, len) };
+
+        Ok(WriteGrant { buf, pipe })
+    }
+}
+
+/// Reading half of a `FlowerPipe`.
+pub struct PipeConsumer<'a, const N: usize> {
+    pipe: &'a FlowerPipe<N>,
+}
+
+impl<'a, const N: usize> PipeConsumer<'a, N> {
+    /// Obtains the contiguous region of bytes that is ready to be read.
+    /// returns `Err` with `WouldBlock` if there is nothing to read.
+    pub fn read(&mut self) -> Result<ReadGrant<'_, N>> {
+        let pipe = self.pipe;
+        let write = pipe.write.load(Ordering::Acquire);
+        let last = pipe.last.load(Ordering::Acquire);
+        let mut read = pipe.read.load(Ordering::Relaxed);
+
+        if read == last && write < read {
+            // Everything up to `last` has been read, continue from the start.
+            read = 0;
+            pipe.read.store(0, Ordering::Release);
+        }
+
+        let end = if write < read { last } else { write };
+        let len = end - read;
+
+        if len == 0 {
+            return Err(would_block());
+        }
+
+        // SAFETY: the region holds committed data,
+        // which the producer will not touch until it is released.
+        let buf = unsafe { pipe.slice_mut(read, len) };
+
+        Ok(ReadGrant { buf, pipe })
+    }
+}
+
+/// Contiguous writable region obtained from `PipeProducer::grant`.
+/// dropping the grant without calling `commit` commits nothing.
+pub struct WriteGrant<'a, const N: usize> {
+    buf: &'a mut [u8],
+    pipe: &'a FlowerPipe<N>,
+}
+
+impl<'a, const N: usize> WriteGrant<'a, N> {
+    /// Makes the first `used` bytes of the grant visible to the consumer.
+    /// `used` is clamped to the length of the grant.
+    pub fn commit(mut self, used: usize) {
+        self.commit_inner(used);
+        std::mem::forget(self);
+    }
+
+    fn commit_inner(&mut self, used: usize) {
+        let pipe = self.pipe;
+        let len = self.buf.len();
+        let used = min(len, used);
+
+        let write = pipe.write.load(Ordering::Acquire);
+        pipe.reserve.fetch_sub(len - used, Ordering::AcqRel);
+
+        let last = pipe.last.load(Ordering::Acquire);
+        let new_write = pipe.reserve.load(Ordering::Acquire);
+
+        if new_write < write && write != N {
+            // We wrapped around while skipping the tail of the buffer,
+            // mark where the readable data ends.
+            pipe.last.store(write, Ordering::Release);
+        } else if new_write > last {
+            // We passed the old end marker, the whole buffer is usable again.
+            pipe.last.store(N, Ordering::Release);
+        }
+
+        // `write` must be updated after `last`,
+        // otherwise the consumer could wrap around too early.
+        pipe.write.store(new_write, Ordering::Release);
+    }
+}
+
+impl<const N: usize> Deref for WriteGrant<'_, N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.buf
+    }
+}
+
+impl<const N: usize> DerefMut for WriteGrant<'_, N> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.buf
+    }
+}
+
+impl<const N: usize> Drop for WriteGrant<'_, N> {
+    fn drop(&mut self) {
+        self.commit_inner(0);
+    }
+}
+
+/// Contiguous readable region obtained from `PipeConsumer::read`.
+/// dropping the grant without calling `release` releases nothing.
+pub struct ReadGrant<'a, const N: usize> {
+    buf: &'a mut [u8],
+    pipe: &'a FlowerPipe<N>,
+}
+
+impl<'a, const N: usize> ReadGrant<'a, N> {
+    /// Frees the first `used` bytes of the grant for the producer.
+    /// `used` is clamped to the length of the grant.
+    pub fn release(self, used: usize) {
+        let used = min(self.buf.len(), used);
+
+        self.pipe.read.fetch_add(used, Ordering::Release);
+    }
+}
+
+impl<const N: usize> Deref for ReadGrant<'_, N> {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.buf
+    }
+}
+
+impl<const N: usize> DerefMut for ReadGrant<'_, N> {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        self.buf
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{FlowerPot, invalid_data, storage_full};
+use std::io::Result;
+use std::mem::size_of;
+
+/// Plain old data that can be reinterpreted as bytes and back.
+///
+/// # Safety
+/// the type must have no padding and no invalid bit patterns,
+/// and must not hold any references or pointers.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! pod {
+    ($($ty:ty),*) => {
+        $(
+            // SAFETY: numeric primitives have no padding and every bit pattern is valid.
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+pod!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+// SAFETY: arrays have no padding between their elements.
+unsafe impl<T: Pod, const K: usize> Pod for [T; K] {}
+
+impl<T: Pod, const N: usize> FlowerPot<T, N> {
+    /// Obtains the initialized items reinterpreted as bytes,
+    /// ready to be written to flash or a socket.
+    pub fn as_bytes(&self) -> &[u8] {
+        let items = self.get_init_slice();
+
+        // SAFETY: `T` is `Pod`, so every byte of the items is initialized.
+        unsafe { std::slice::from_raw_parts(items.as_ptr().cast::<u8>(), size_of_val(items)) }
+    }
+
+    /// Obtains the initialized items reinterpreted as mutable bytes,
+    /// so they can be filled in place, such as by DMA.
+    pub fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let items = self.get_init_slice_mut();
+        let len = size_of_val(items);
+
+        // SAFETY: `T` is `Pod`, so every byte is initialized
+        // and any bytes written through the slice form valid items.
+        unsafe { std::slice::from_raw_parts_mut(items.as_mut_ptr().cast::<u8>(), len) }
+    }
+
+    /// Grows the `FlowerPot` to `len` items, the new ones having every byte zeroed.
+    /// does nothing if it already holds `len` or more items.
+    /// returns `Err` with `StorageFull` if `len` is bigger than `N`, in which case nothing is pushed.
+    pub fn resize_zeroed(&mut self, len: usize) -> Result<()> {
+        if len > N {
+            self.record_rejected();
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        while self.pos < len {
+            // SAFETY: `T` is `Pod`, so the zero bit pattern is a valid item.
+            let item = unsafe { std::mem::zeroed() };
+            self.push(item)?;
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new `FlowerPot` from items previously obtained through `as_bytes`.
+    /// `bytes` does not need to be aligned for `T`.
+    /// returns `Err` with `InvalidData` if its length is not a multiple of the size of `T`
+    /// and `StorageFull` if it holds more than `N` items.
+    pub fn from_bytes(bytes: &[u8]) -> Result<FlowerPot<T, N>> {
+        let size = size_of::<T>().max(1);
+
+        if !bytes.len().is_multiple_of(size) {
+            let err = invalid_data();
+
+            return Err(err);
+        }
+
+        if bytes.len() / size > N {
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        let items = bytes.chunks_exact(size).map(|chunk| {
+            // SAFETY: `chunk` holds exactly `size_of::<T>()` bytes,
+            // and every bit pattern is valid for `Pod` types.
+            unsafe { chunk.as_ptr().cast::<T>().read_unaligned() }
+        });
+
+        Self::try_from_iter(items)
+    }
+}
@@ -0,0 +1,209 @@
+use crate::{FlowerPot, storage_full};
+use std::cell::{RefCell, UnsafeCell};
+use std::io::Result;
+use std::mem;
+
+/// Pool of `SEGMENTS` pots of `N` items each,
+/// lent out to `PooledPotChain`s so they can grow without allocating.
+/// every chain borrows the pool, so it can not be moved or dropped while they exist.
+pub struct PotPool<T, const N: usize, const SEGMENTS: usize> {
+    segments: [UnsafeCell<FlowerPot<T, N>>; SEGMENTS],
+    /// Indexes of the segments no chain is holding.
+    free: RefCell<FlowerPot<usize, SEGMENTS>>,
+}
+
+impl<T, const N: usize, const SEGMENTS: usize> PotPool<T, N, SEGMENTS> {
+    /// Creates a new `PotPool` with every segment free.
+    /// fails to compile if `N` is 0.
+    pub fn new() -> PotPool<T, N, SEGMENTS> {
+        const { assert!(N > 0, "`PotPool` segments must hold at least 1 item") };
+
+        let mut free = FlowerPot::new();
+        free.push_iter((0..SEGMENTS).rev());
+
+        Self {
+            segments: [const { UnsafeCell::new(FlowerPot::new()) }; SEGMENTS],
+            free: RefCell::new(free),
+        }
+    }
+
+    /// Returns the amount of segments no chain is holding.
+    pub fn available(&self) -> usize {
+        self.free.borrow().len()
+    }
+
+    /// Creates a new empty chain taking its segments from this pool.
+    pub fn chain(&self) -> PooledPotChain<'_, T, N, SEGMENTS> {
+        PooledPotChain {
+            pool: self,
+            segments: FlowerPot::new(),
+            len: 0,
+        }
+    }
+
+    fn acquire(&self) -> Option<usize> {
+        self.free.borrow_mut().pop()
+    }
+
+    fn release(&self, index: usize) {
+        // Can not fail, every index is only ever released once.
+        let _ = self.free.borrow_mut().push(index);
+    }
+}
+
+impl<T, const N: usize, const SEGMENTS: usize> Default for PotPool<T, N, SEGMENTS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Sequence of items stored in a chain of pots taken from a `PotPool`,
+/// the counterpart of `PotChain` for builds without `alloc`.
+/// grows one `FlowerPot` of `N` items at a time until the pool runs out,
+/// and hands its segments back when they are emptied or the chain is dropped.
+pub struct PooledPotChain<'p, T, const N: usize, const SEGMENTS: usize> {
+    pool: &'p PotPool<T, N, SEGMENTS>,
+    /// Indexes of the segments in the pool, in order.
+    segments: FlowerPot<usize, SEGMENTS>,
+    len: usize,
+}
+
+impl<T, const N: usize, const SEGMENTS: usize> PooledPotChain<'_, T, N, SEGMENTS> {
+    /// Returns the amount of items in every segment combined.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the chain holds no items.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the amount of segments.
+    #[inline]
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Pushes an item to the last segment,
+    /// taking a new one from the pool if it is full.
+    /// returns `Err` with `StorageFull` if the pool has no segments left.
+    pub fn push(&mut self, item: T) -> Result<()> {
+        let index = match self.segments.get_init_slice().last() {
+            Some(&index) if !self.segment(index).is_full() => index,
+            _ => {
+                let Some(index) = self.pool.acquire() else {
+                    let err = storage_full();
+
+                    return Err(err);
+                };
+
+                // Can not fail, there are only `SEGMENTS` indexes.
+                let _ = self.segments.push(index);
+
+                index
+            }
+        };
+
+        // Can not fail, the segment is not full.
+        let _ = self.segment_mut(index).push(item);
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Pops the last item.
+    /// returns `None` if the chain is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let index = *self.segments.get_init_slice().last()?;
+        let segment = self.segment_mut(index);
+        let item = segment.pop();
+
+        if segment.is_empty() {
+            self.segments.pop();
+            self.pool.release(index);
+        }
+
+        self.len -= item.is_some() as usize;
+
+        item
+    }
+
+    /// Moves the items of `pot` into a new segment from the pool.
+    /// does nothing if it is empty.
+    /// returns `Err` with `StorageFull` if the pool has no segments left.
+    pub fn append_pot(&mut self, pot: FlowerPot<T, N>) -> Result<()> {
+        if pot.is_empty() {
+            return Ok(());
+        }
+
+        let Some(index) = self.pool.acquire() else {
+            let err = storage_full();
+
+            return Err(err);
+        };
+
+        self.len += pot.len();
+        *self.segment_mut(index) = pot;
+
+        // Can not fail, there are only `SEGMENTS` indexes.
+        let _ = self.segments.push(index);
+
+        Ok(())
+    }
+
+    /// Takes the items of the first segment out of the chain,
+    /// handing the segment back to the pool.
+    /// returns `None` if the chain is empty.
+    pub fn pop_segment(&mut self) -> Option<FlowerPot<T, N>> {
+        let index = self.segments.remove(0)?;
+        let segment = mem::take(self.segment_mut(index));
+
+        self.len -= segment.len();
+        self.pool.release(index);
+
+        Some(segment)
+    }
+
+    /// Iterates over the items of every segment in order.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.segments().flatten()
+    }
+
+    /// Iterates over the segments in order.
+    pub fn segments(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.segments
+            .get_init_slice()
+            .iter()
+            .map(|&index| self.segment(index).get_init_slice())
+    }
+
+    /// Drops every item and hands every segment back to the pool.
+    pub fn clear(&mut self) {
+        while let Some(index) = self.segments.pop() {
+            self.segment_mut(index).clear();
+            self.pool.release(index);
+        }
+
+        self.len = 0;
+    }
+
+    fn segment(&self, index: usize) -> &FlowerPot<T, N> {
+        // SAFETY: the segment was taken from the pool by this chain,
+        // no other chain can access it until it is released.
+        unsafe { &*self.pool.segments[index].get() }
+    }
+
+    fn segment_mut(&mut self, index: usize) -> &mut FlowerPot<T, N> {
+        // SAFETY: see `segment`, the chain is borrowed mutably.
+        unsafe { &mut *self.pool.segments[index].get() }
+    }
+}
+
+impl<T, const N: usize, const SEGMENTS: usize> Drop for PooledPotChain<'_, T, N, SEGMENTS> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
@@ -0,0 +1,150 @@
+use crate::{FlowerPot, FlowerPotWithOverflow};
+use std::io::Result;
+
+/// Fixed-capacity container of items,
+/// so code can be generic over the storage flavor.
+/// implemented by `FlowerPot`, `FlowerPotWithOverflow`
+/// and mutable references to any `Pot`, such as the one handed out by `StaticPot::take`.
+/// the trait is object-safe, so code can also work through `&mut dyn Pot<T>`
+/// instead of being instantiated for every capacity.
+pub trait Pot<T> {
+    /// Pushes an item to the container.
+    /// returns `Err` if the container is full.
+    fn push(&mut self, item: T) -> Result<()>;
+
+    /// Pops the last item from the container.
+    /// returns `None` if the container is empty.
+    fn pop(&mut self) -> Option<T>;
+
+    /// Returns the current amount of items.
+    fn len(&self) -> usize;
+
+    /// Returns the total amount of items the container can hold.
+    fn capacity(&self) -> usize;
+
+    /// Obtains an immutable reference to the items.
+    fn as_slice(&self) -> &[T];
+
+    /// Obtains a mutable reference to the items.
+    fn as_mut_slice(&mut self) -> &mut [T];
+
+    /// Returns `true` if the container holds no items.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if the container can not hold any more items.
+    fn is_full(&self) -> bool {
+        self.len() >= self.capacity()
+    }
+
+    /// Returns the amount of items that can still be pushed.
+    fn remaining_capacity(&self) -> usize {
+        self.capacity() - self.len()
+    }
+
+    /// Drops every item in the container.
+    fn clear(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
+
+/// `Pot` with its capacity erased,
+/// so code can work through `&mut DynPot<T>` instead of being instantiated for every capacity.
+pub type DynPot<T> = dyn Pot<T>;
+
+impl<T, const N: usize> Pot<T> for FlowerPot<T, N> {
+    fn push(&mut self, item: T) -> Result<()> {
+        FlowerPot::push(self, item)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        FlowerPot::pop(self)
+    }
+
+    fn len(&self) -> usize {
+        FlowerPot::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self.get_init_slice()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.get_init_slice_mut()
+    }
+
+    fn clear(&mut self) {
+        FlowerPot::clear(self)
+    }
+}
+
+impl<T, P: Pot<T> + ?Sized> Pot<T> for &mut P {
+    fn push(&mut self, item: T) -> Result<()> {
+        (**self).push(item)
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        (**self).pop()
+    }
+
+    fn len(&self) -> usize {
+        (**self).len()
+    }
+
+    fn capacity(&self) -> usize {
+        (**self).capacity()
+    }
+
+    fn as_slice(&self) -> &[T] {
+        (**self).as_slice()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        (**self).as_mut_slice()
+    }
+
+    fn clear(&mut self) {
+        (**self).clear()
+    }
+}
+
+impl<T, const N: usize, F> Pot<T> for FlowerPotWithOverflow<T, N, F>
+where
+    F: FnMut(&mut FlowerPot<T, N>, T),
+{
+    /// Never fails, items pushed to a full pot are handed to the overflow callback.
+    fn push(&mut self, item: T) -> Result<()> {
+        FlowerPotWithOverflow::push(self, item);
+
+        Ok(())
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        FlowerPotWithOverflow::pop(self)
+    }
+
+    fn len(&self) -> usize {
+        FlowerPot::len(self)
+    }
+
+    fn capacity(&self) -> usize {
+        N
+    }
+
+    fn as_slice(&self) -> &[T] {
+        self.get_init_slice()
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T] {
+        self.pot_mut().get_init_slice_mut()
+    }
+
+    fn clear(&mut self) {
+        self.pot_mut().clear()
+    }
+}
@@ -0,0 +1,9 @@
+//! Commonly used types and traits.
+//!
+//! `use flowerpot::prelude::*;` brings the core containers into scope,
+//! along with the traits whose methods they are used through.
+
+pub use crate::{
+    Checksum, FlatPots, FlowerMap, FlowerPot, FlowerString, IteratorExt, Line, Page, Pod, Pot,
+    SmallPot4, SmallPot8, SmallPot16, TryClone, TryCollectInto, TryExtend, WritePot, write_pot,
+};
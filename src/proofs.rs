@@ -0,0 +1,102 @@
+//! Kani harnesses for the unsafe core of `FlowerPot`,
+//! run with `cargo kani --features verification`.
+//!
+//! these are proof obligations, not proofs yet:
+//! no harness has been run under Kani so far,
+//! so none of them should be taken as machine-checked until it has.
+
+use crate::FlowerPot;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+const N: usize = 4;
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn push_pop_round_trip() {
+    let mut pot = FlowerPot::<u8, N>::new();
+    let count: usize = kani::any();
+    kani::assume(count <= N + 1);
+
+    for i in 0..count {
+        let pushed = pot.push(i as u8).is_ok();
+        assert!(pushed == (i < N));
+    }
+
+    assert!(pot.len() == count.min(N));
+
+    for i in (0..count.min(N)).rev() {
+        assert!(pot.pop() == Some(i as u8));
+    }
+
+    assert!(pot.pop().is_none());
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn get_stays_in_bounds() {
+    let mut pot = FlowerPot::<u8, N>::new();
+    let count: usize = kani::any();
+    kani::assume(count <= N);
+
+    for _ in 0..count {
+        let _ = pot.push(kani::any());
+    }
+
+    let index: usize = kani::any();
+
+    assert!(pot.get(index).is_some() == (index < count));
+    assert!(pot.get_mut(index).is_some() == (index < count));
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn insert_remove_keep_order() {
+    let mut pot = FlowerPot::<u8, N>::new();
+    let _ = pot.push_array([1, 2, 3]);
+
+    let index: usize = kani::any();
+    let inserted = pot.insert(index, 9).is_ok();
+
+    assert!(inserted == (index <= 3));
+
+    if inserted {
+        assert!(pot[index] == 9);
+        assert!(pot.remove(index) == Some(9));
+    }
+
+    assert!(pot[..] == [1, 2, 3]);
+}
+
+static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+struct Counted;
+
+impl Drop for Counted {
+    fn drop(&mut self) {
+        DROPS.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[kani::proof]
+#[kani::unwind(6)]
+fn drops_every_item_once() {
+    let count: usize = kani::any();
+    let popped: usize = kani::any();
+    kani::assume(count <= N && popped <= count);
+
+    {
+        let mut pot = FlowerPot::<Counted, N>::new();
+
+        for _ in 0..count {
+            let _ = pot.push(Counted);
+        }
+
+        for _ in 0..popped {
+            drop(pot.pop());
+        }
+
+        assert!(DROPS.load(Ordering::Relaxed) == popped);
+    }
+
+    assert!(DROPS.load(Ordering::Relaxed) == count);
+}
@@ -0,0 +1,69 @@
+use crate::FlowerPot;
+
+/// Token bucket holding up to `N` tokens,
+/// every token comes back `window` ticks after it was spent.
+/// this allows bursts of up to `N` events but never more than `N` events in any `window`.
+/// the times are ticks of any monotonic clock, passed in by the caller.
+pub struct RateLimiter<const N: usize> {
+    /// Times at which the spent tokens were taken, oldest first.
+    spent: FlowerPot<u64, N>,
+    window: u64,
+}
+
+impl<const N: usize> RateLimiter<N> {
+    /// Creates a new `RateLimiter` with every token available.
+    pub fn new(window: u64) -> RateLimiter<N> {
+        Self {
+            spent: FlowerPot::new(),
+            window,
+        }
+    }
+
+    /// Takes a token at `now`.
+    /// returns `false` if none is available, in which case the event should be dropped.
+    pub fn try_acquire(&mut self, now: u64) -> bool {
+        self.refill(now);
+
+        self.spent.push(now).is_ok()
+    }
+
+    /// Returns the amount of tokens available at `now`.
+    pub fn available(&self, now: u64) -> usize {
+        N - self.spent.len() + self.refilled(now)
+    }
+
+    /// Returns the amount of ticks after `now` until a token is available,
+    /// which is `0` if one is available already
+    /// and `u64::MAX` if there are no tokens at all.
+    pub fn wait_time(&self, now: u64) -> u64 {
+        if self.available(now) > 0 {
+            return 0;
+        }
+
+        let Some(&oldest) = self.spent.get(0) else {
+            return u64::MAX;
+        };
+
+        oldest.saturating_add(self.window).saturating_sub(now)
+    }
+
+    /// Makes every token available again.
+    pub fn reset(&mut self) {
+        self.spent.clear();
+    }
+
+    /// Returns the amount of spent tokens that came back at `now`.
+    fn refilled(&self, now: u64) -> usize {
+        self.spent
+            .get_init_slice()
+            .iter()
+            .take_while(|spent| now.saturating_sub(**spent) >= self.window)
+            .count()
+    }
+
+    fn refill(&mut self, now: u64) {
+        let refilled = self.refilled(now);
+
+        self.spent.consume(refilled);
+    }
+}
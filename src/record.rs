@@ -0,0 +1,174 @@
+use crate::{FlowerPot, WritePot, storage_full};
+use std::fmt;
+use std::io::Result;
+
+/// Writer appending delimited records of fields to a `FlowerPot` of bytes,
+/// every record ends with `\n`.
+/// a field holding the delimiter, a quote or a line break
+/// is quoted, with its quotes doubled.
+/// a record that was not ended is removed on drop,
+/// so the `FlowerPot` only ever keeps complete lines.
+pub struct RecordWriter<'a, const N: usize> {
+    pot: &'a mut FlowerPot<u8, N>,
+    delimiter: u8,
+    /// End of the last complete line.
+    complete: usize,
+    /// Amount of fields in the current record.
+    fields: usize,
+}
+
+impl<'a, const N: usize> RecordWriter<'a, N> {
+    /// Creates a new `RecordWriter` appending comma separated records to `pot`.
+    /// the bytes already in `pot` count as complete lines.
+    pub fn new(pot: &'a mut FlowerPot<u8, N>) -> RecordWriter<'a, N> {
+        Self::with_delimiter(pot, b',')
+    }
+
+    /// Creates a new `RecordWriter` appending records separated by `delimiter` to `pot`.
+    /// the bytes already in `pot` count as complete lines.
+    pub fn with_delimiter(pot: &'a mut FlowerPot<u8, N>, delimiter: u8) -> RecordWriter<'a, N> {
+        let complete = pot.len();
+
+        Self {
+            pot,
+            delimiter,
+            complete,
+            fields: 0,
+        }
+    }
+
+    /// Appends `bytes` as the next field of the current record, escaping it if needed.
+    /// returns `Err` with `StorageFull` if it does not fit, in which case nothing is appended.
+    pub fn field(&mut self, bytes: &[u8]) -> Result<()> {
+        let start = self.start_field()?;
+
+        if let Err(err) = self.pot.put_slice(bytes) {
+            self.pot.truncate(start);
+
+            return Err(err);
+        }
+
+        self.finish_field(start)
+    }
+
+    /// Appends `value` formatted with `Display` as the next field of the current record,
+    /// escaping it if needed.
+    /// returns `Err` with `StorageFull` if it does not fit, in which case nothing is appended.
+    pub fn field_display<T: fmt::Display>(&mut self, value: &T) -> Result<()> {
+        let start = self.start_field()?;
+
+        if self
+            .pot
+            .write_pot_fmt(format_args!("{value}"))
+            .is_truncated()
+        {
+            self.pot.truncate(start);
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        self.finish_field(start)
+    }
+
+    /// Ends the current record with `\n`.
+    /// returns `Err` with `StorageFull` if it does not fit, in which case the record stays open,
+    /// so it can be retried after `clear_complete`.
+    pub fn end_record(&mut self) -> Result<()> {
+        self.pot.push(b'\n')?;
+        self.complete = self.pot.len();
+        self.fields = 0;
+
+        Ok(())
+    }
+
+    /// Removes the fields of the current record.
+    pub fn discard_record(&mut self) {
+        self.pot.truncate(self.complete);
+        self.fields = 0;
+    }
+
+    /// Obtains an immutable reference to every complete line.
+    pub fn complete_lines(&self) -> &[u8] {
+        &self.pot.get_init_slice()[..self.complete]
+    }
+
+    /// Removes every complete line, moving the current record to the front.
+    /// call this after the lines were written out.
+    pub fn clear_complete(&mut self) {
+        self.pot.consume(self.complete);
+        self.complete = 0;
+    }
+
+    /// Returns the amount of fields in the current record.
+    #[inline]
+    pub const fn fields(&self) -> usize {
+        self.fields
+    }
+
+    /// Appends the delimiter unless this is the first field of the record.
+    /// returns where the field starts, including the delimiter.
+    fn start_field(&mut self) -> Result<usize> {
+        let start = self.pot.len();
+
+        if self.fields > 0 {
+            self.pot.push(self.delimiter)?;
+        }
+
+        Ok(start)
+    }
+
+    /// Quotes the field written after `start` if it needs to be.
+    fn finish_field(&mut self, start: usize) -> Result<()> {
+        let delimiter = self.delimiter;
+        let first = if self.fields > 0 { start + 1 } else { start };
+        let end = self.pot.len();
+
+        let field = &self.pot.get_init_slice()[first..end];
+        let special = |byte: &u8| matches!(*byte, b'"' | b'\r' | b'\n') || *byte == delimiter;
+
+        if field.iter().any(special) {
+            let quotes = field.iter().filter(|byte| **byte == b'"').count();
+
+            // Grow by the two enclosing quotes and a second one for every quote,
+            // then move the field into place from the back.
+            let grow = quotes + 2;
+
+            if grow > self.pot.remaining_capacity() {
+                self.pot.truncate(start);
+                let err = storage_full();
+
+                return Err(err);
+            }
+
+            self.pot.push_iter(std::iter::repeat_n(0, grow));
+
+            let bytes = self.pot.get_init_slice_mut();
+            let mut write = bytes.len() - 1;
+            bytes[write] = b'"';
+
+            for read in (first..end).rev() {
+                let byte = bytes[read];
+                write -= 1;
+                bytes[write] = byte;
+
+                if byte == b'"' {
+                    write -= 1;
+                    bytes[write] = b'"';
+                }
+            }
+
+            bytes[write - 1] = b'"';
+        }
+
+        self.fields += 1;
+
+        Ok(())
+    }
+}
+
+impl<const N: usize> Drop for RecordWriter<'_, N> {
+    fn drop(&mut self) {
+        self.discard_record();
+    }
+}
@@ -0,0 +1,99 @@
+use crate::FlowerPot;
+use std::ptr;
+
+/// Draining iterator returned by `FlowerPot::recycle`.
+/// items are moved out in order and can be refurbished
+/// and handed back through `put_back` instead of being dropped.
+/// items that were not yielded yet are kept when the iterator is dropped,
+/// following the ones that were put back.
+pub struct RecycleIter<'a, T, const N: usize> {
+    pot: &'a mut FlowerPot<T, N>,
+    /// Index of the next item to yield.
+    read: usize,
+    /// Index of the next slot to put an item back into.
+    write: usize,
+    /// Length of the pot before recycling started.
+    end: usize,
+}
+
+impl<'a, T, const N: usize> RecycleIter<'a, T, N> {
+    pub(crate) fn new(pot: &'a mut FlowerPot<T, N>) -> RecycleIter<'a, T, N> {
+        let end = pot.pos;
+
+        // Items are moved in and out of the pot while recycling,
+        // leaking them on a panic is preferable to dropping them twice.
+        pot.pos = 0;
+
+        Self {
+            pot,
+            read: 0,
+            write: 0,
+            end,
+        }
+    }
+
+    /// Moves `item` back into the `FlowerPot`, after the items put back before it.
+    /// returns `Some` with `item` if as many items were put back as were yielded.
+    pub fn put_back(&mut self, item: T) -> Option<T> {
+        if self.write == self.read {
+            return Some(item);
+        }
+
+        // SAFETY: the slot at `write` was moved out of by `next`.
+        unsafe { (*self.pot.items.as_mut_ptr().add(self.write)).write(item) };
+        self.write += 1;
+
+        None
+    }
+
+    /// Returns the amount of items that were put back.
+    pub fn put_back_len(&self) -> usize {
+        self.write
+    }
+}
+
+impl<T, const N: usize> Iterator for RecycleIter<'_, T, N> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.read == self.end {
+            return None;
+        }
+
+        // SAFETY: the slot at `read` holds an item that was not yielded yet.
+        let item = unsafe { self.pot.items[self.read].assume_init_read() };
+        self.read += 1;
+
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.read;
+
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for RecycleIter<'_, T, N> {}
+
+impl<T, const N: usize> Drop for RecycleIter<'_, T, N> {
+    fn drop(&mut self) {
+        let rest = self.end - self.read;
+        let items = self.pot.items.as_mut_ptr();
+
+        // SAFETY: `read..end` holds the items that were not yielded,
+        // they are moved right behind the ones that were put back.
+        unsafe { ptr::copy(items.add(self.read), items.add(self.write), rest) };
+
+        self.pot.pos = self.write + rest;
+        self.pot.check_invariants();
+    }
+}
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Drains every item in order through a `RecycleIter`,
+    /// which allows putting them back without dropping them.
+    pub fn recycle(&mut self) -> RecycleIter<'_, T, N> {
+        RecycleIter::new(self)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::FlowerPot;
+
+impl<T: Ord, const N: usize> FlowerPot<T, N> {
+    /// Obtains an immutable reference to the smallest item.
+    /// returns `None` if the `FlowerPot` is empty.
+    pub fn min(&self) -> Option<&T> {
+        self.get_init_slice().iter().min()
+    }
+
+    /// Obtains an immutable reference to the biggest item.
+    /// returns `None` if the `FlowerPot` is empty.
+    pub fn max(&self) -> Option<&T> {
+        self.get_init_slice().iter().max()
+    }
+
+    /// Reorders the items so the one at `index` is where it would be if they were sorted,
+    /// with smaller or equal items before it and bigger or equal ones after it.
+    /// returns those three parts, or `None` if `index` is out of bounds.
+    pub fn select_nth_unstable(&mut self, index: usize) -> Option<(&mut [T], &mut T, &mut [T])> {
+        if index >= self.pos {
+            return None;
+        }
+
+        Some(self.get_init_slice_mut().select_nth_unstable(index))
+    }
+
+    /// Returns the median of the items, reordering them as `select_nth_unstable` does.
+    /// for an even amount of items this is the lower of the two middle ones,
+    /// returns `None` if the `FlowerPot` is empty.
+    pub fn median(&mut self) -> Option<T>
+    where
+        T: Copy,
+    {
+        let index = self.pos.checked_sub(1)? / 2;
+        let (_, median, _) = self.select_nth_unstable(index)?;
+
+        Some(*median)
+    }
+}
+
+macro_rules! int_reductions {
+    ($($int:ty),*) => {
+        $(
+            impl<const N: usize> FlowerPot<$int, N> {
+                /// Adds up every item.
+                /// returns `None` if the sum overflows.
+                pub fn sum_checked(&self) -> Option<$int> {
+                    self.get_init_slice()
+                        .iter()
+                        .try_fold(0 as $int, |sum, &item| sum.checked_add(item))
+                }
+
+                /// Adds up every item,
+                /// clamping the running sum at the numeric bounds instead of overflowing.
+                pub fn sum_saturating(&self) -> $int {
+                    self.get_init_slice()
+                        .iter()
+                        .fold(0 as $int, |sum, &item| sum.saturating_add(item))
+                }
+            }
+        )*
+    };
+}
+
+int_reductions!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
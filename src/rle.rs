@@ -0,0 +1,91 @@
+use crate::FlowerPot;
+use std::io::Result;
+
+/// Run-length encoded sequence stored in a `FlowerPot` of runs,
+/// can store up to `N` runs of equal adjacent items.
+#[derive(Debug, Default)]
+pub struct FlowerRle<T, const N: usize> {
+    runs: FlowerPot<(T, usize), N>,
+    len: usize,
+}
+
+impl<T: Eq, const N: usize> FlowerRle<T, N> {
+    /// Creates a new empty `FlowerRle`.
+    pub fn new() -> FlowerRle<T, N> {
+        Self {
+            runs: FlowerPot::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the amount of items in the sequence.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the sequence holds no items.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the amount of runs the sequence is stored in.
+    #[inline]
+    pub const fn run_count(&self) -> usize {
+        self.runs.len()
+    }
+
+    /// Pushes an item to the sequence,
+    /// extending the last run if it holds an equal item.
+    /// returns `Err` if a new run is needed and all `N` are in use.
+    pub fn push(&mut self, item: T) -> Result<()> {
+        match self.runs.get_init_slice_mut().last_mut() {
+            Some((last, count)) if *last == item => *count += 1,
+            _ => self.runs.push((item, 1))?,
+        }
+
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// Pops the last item from the sequence.
+    /// returns `None` if the sequence is empty.
+    pub fn pop(&mut self) -> Option<T>
+    where
+        T: Clone,
+    {
+        let (last, count) = self.runs.get_init_slice_mut().last_mut()?;
+
+        let item = if *count > 1 {
+            *count -= 1;
+            last.clone()
+        } else {
+            self.runs.pop()?.0
+        };
+
+        self.len -= 1;
+
+        Some(item)
+    }
+
+    /// Obtains an immutable reference to the runs as `(item, count)` pairs.
+    pub fn runs(&self) -> &[(T, usize)] {
+        self.runs.get_init_slice()
+    }
+
+    /// Returns an iterator over every item of the sequence,
+    /// repeating each run's item as often as it occurs.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.runs()
+            .iter()
+            .flat_map(|(item, count)| std::iter::repeat_n(item, *count))
+    }
+
+    /// Drops every item in the sequence.
+    pub fn clear(&mut self) {
+        self.runs.clear();
+        self.len = 0;
+    }
+}
@@ -0,0 +1,75 @@
+use crate::FlowerPot;
+use std::io::Result;
+use std::ops::{Deref, DerefMut};
+
+/// Guard returned by `FlowerPot::push_scoped`.
+/// dereferences to the pot, so nested scopes can push through it,
+/// and pops the scoped item along with everything pushed after it when dropped.
+pub struct PopGuard<'a, T, const N: usize> {
+    pot: &'a mut FlowerPot<T, N>,
+    index: usize,
+}
+
+impl<T, const N: usize> PopGuard<'_, T, N> {
+    /// Returns the index of the scoped item.
+    #[inline]
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Obtains an immutable reference to the scoped item.
+    /// returns `None` if it was already removed through the guard.
+    pub fn item(&self) -> Option<&T> {
+        self.pot.get(self.index)
+    }
+
+    /// Obtains a mutable reference to the scoped item.
+    /// returns `None` if it was already removed through the guard.
+    pub fn item_mut(&mut self) -> Option<&mut T> {
+        self.pot.get_mut(self.index)
+    }
+
+    /// Pops the scoped item and returns it,
+    /// dropping everything pushed after it.
+    /// returns `None` if it was already removed through the guard.
+    pub fn pop(self) -> Option<T> {
+        self.pot.truncate(self.index + 1);
+
+        if self.pot.len() == self.index + 1 {
+            self.pot.pop()
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for PopGuard<'_, T, N> {
+    type Target = FlowerPot<T, N>;
+
+    fn deref(&self) -> &FlowerPot<T, N> {
+        self.pot
+    }
+}
+
+impl<T, const N: usize> DerefMut for PopGuard<'_, T, N> {
+    fn deref_mut(&mut self) -> &mut FlowerPot<T, N> {
+        self.pot
+    }
+}
+
+impl<T, const N: usize> Drop for PopGuard<'_, T, N> {
+    fn drop(&mut self) {
+        self.pot.truncate(self.index);
+    }
+}
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Pushes an item that is popped again when the returned guard is dropped.
+    /// returns `Err` if the `FlowerPot` is full.
+    pub fn push_scoped(&mut self, item: T) -> Result<PopGuard<'_, T, N>> {
+        let index = self.pos;
+        self.push(item)?;
+
+        Ok(PopGuard { pot: self, index })
+    }
+}
@@ -0,0 +1,63 @@
+use crate::FlowerPot;
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// `FlowerPot` meant to live in a `static`, usually declared through `static_pot_in_section!`.
+/// the pot is handed out once as a `&'static mut` through `take`,
+/// so no `static mut` is needed to mutate it.
+/// its initial state consists of zero bytes only,
+/// so it is correctly initialized in any section the startup code zeroes, like `.bss`.
+pub struct StaticPot<T, const N: usize> {
+    pot: UnsafeCell<FlowerPot<T, N>>,
+    taken: AtomicBool,
+}
+
+// SAFETY: The pot is only ever reachable through the single reference `take` hands out.
+unsafe impl<T: Send, const N: usize> Sync for StaticPot<T, N> {}
+
+impl<T, const N: usize> StaticPot<T, N> {
+    /// Creates a new `StaticPot` holding an empty `FlowerPot`.
+    pub const fn new() -> StaticPot<T, N> {
+        Self {
+            pot: UnsafeCell::new(FlowerPot::new()),
+            taken: AtomicBool::new(false),
+        }
+    }
+
+    /// Obtains the only mutable reference to the `FlowerPot`.
+    /// returns `None` if it was taken before.
+    #[allow(clippy::mut_from_ref)]
+    pub fn take(&'static self) -> Option<&'static mut FlowerPot<T, N>> {
+        if self.taken.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+
+        // SAFETY: `taken` was `false`, so no other reference was handed out
+        // and none will be afterwards.
+        Some(unsafe { &mut *self.pot.get() })
+    }
+
+    /// Returns `true` if the `FlowerPot` was taken.
+    pub fn is_taken(&self) -> bool {
+        self.taken.load(Ordering::Acquire)
+    }
+}
+
+impl<T, const N: usize> Default for StaticPot<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Declares a `static` `StaticPot` placed in the given linker section,
+/// such as a DMA capable or core coupled RAM region.
+/// the section must be zeroed at startup, see `StaticPot`.
+/// `static_pot_in_section!(".dma_buffers", pub RX: FlowerPot<u8, 512>);`
+#[macro_export]
+macro_rules! static_pot_in_section {
+    ($section:literal, $(#[$attr:meta])* $vis:vis $name:ident: FlowerPot<$ty:ty, $n:tt>) => {
+        $(#[$attr])*
+        #[unsafe(link_section = $section)]
+        $vis static $name: $crate::StaticPot<$ty, $n> = $crate::StaticPot::new();
+    };
+}
@@ -0,0 +1,148 @@
+use crate::FlowerPot;
+use std::cell::UnsafeCell;
+use std::mem::MaybeUninit;
+use std::ptr;
+use std::sync::atomic::{self, AtomicUsize, Ordering};
+
+/// `FlowerPot` of `Copy` items shared through a sequence lock.
+/// one writer updates the items without ever blocking,
+/// while any amount of readers take snapshots and retry on torn reads.
+pub struct SeqLockPot<T: Copy, const N: usize> {
+    pot: UnsafeCell<FlowerPot<T, N>>,
+    /// Odd while a write is in progress, bumped twice by every write.
+    seq: AtomicUsize,
+}
+
+// SAFETY: Readers only keep copies that were validated against `seq`,
+// and there is only ever one writer.
+unsafe impl<T: Copy + Send, const N: usize> Sync for SeqLockPot<T, N> {}
+
+impl<T: Copy, const N: usize> SeqLockPot<T, N> {
+    /// Creates a new empty `SeqLockPot`.
+    pub fn new() -> SeqLockPot<T, N> {
+        Self {
+            pot: UnsafeCell::new(FlowerPot::new()),
+            seq: AtomicUsize::new(0),
+        }
+    }
+
+    /// Splits the pot into the writer
+    /// and a reader that can be cloned and shared between threads.
+    pub fn split(&mut self) -> (SeqLockWriter<'_, T, N>, SeqLockReader<'_, T, N>) {
+        let lock = &*self;
+
+        (SeqLockWriter { lock }, SeqLockReader { lock })
+    }
+
+    /// Consumes the lock and returns the pot inside of it.
+    pub fn into_inner(self) -> FlowerPot<T, N> {
+        self.pot.into_inner()
+    }
+}
+
+impl<T: Copy, const N: usize> Default for SeqLockPot<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Writing half of a `SeqLockPot`.
+pub struct SeqLockWriter<'a, T: Copy, const N: usize> {
+    lock: &'a SeqLockPot<T, N>,
+}
+
+impl<T: Copy, const N: usize> SeqLockWriter<'_, T, N> {
+    /// Calls `f` with mutable access to the pot,
+    /// readers retry until it returns or unwinds.
+    pub fn write<R, F: FnOnce(&mut FlowerPot<T, N>) -> R>(&mut self, f: F) -> R {
+        let lock = self.lock;
+        let seq = lock.seq.load(Ordering::Relaxed);
+
+        lock.seq.store(seq.wrapping_add(1), Ordering::Relaxed);
+        atomic::fence(Ordering::Release);
+
+        let _unlock = Unlock {
+            seq: &lock.seq,
+            next: seq.wrapping_add(2),
+        };
+
+        // SAFETY: we are the only writer,
+        // readers discard whatever they copied while `seq` is odd.
+        f(unsafe { &mut *lock.pot.get() })
+    }
+
+    /// Obtains an immutable reference to the pot,
+    /// which can not change while the writer is borrowed.
+    pub fn get_ref(&self) -> &FlowerPot<T, N> {
+        // SAFETY: only the writer mutates the pot.
+        unsafe { &*self.lock.pot.get() }
+    }
+}
+
+/// Ends a write when dropped, even if the closure panicked,
+/// so readers never spin on an odd `seq` forever.
+struct Unlock<'a> {
+    seq: &'a AtomicUsize,
+    next: usize,
+}
+
+impl Drop for Unlock<'_> {
+    fn drop(&mut self) {
+        self.seq.store(self.next, Ordering::Release);
+    }
+}
+
+/// Reading half of a `SeqLockPot`.
+pub struct SeqLockReader<'a, T: Copy, const N: usize> {
+    lock: &'a SeqLockPot<T, N>,
+}
+
+impl<T: Copy, const N: usize> SeqLockReader<'_, T, N> {
+    /// Takes a consistent snapshot of the pot,
+    /// spinning while a write is in progress.
+    pub fn read(&self) -> FlowerPot<T, N> {
+        loop {
+            if let Some(pot) = self.try_read() {
+                return pot;
+            }
+
+            std::hint::spin_loop();
+        }
+    }
+
+    /// Takes a snapshot of the pot.
+    /// returns `None` if a write was in progress or happened during the copy.
+    pub fn try_read(&self) -> Option<FlowerPot<T, N>> {
+        let lock = self.lock;
+        let seq = lock.seq.load(Ordering::Acquire);
+
+        if seq % 2 == 1 {
+            return None;
+        }
+
+        // SAFETY: the copy may be torn, which is why it is kept
+        // in a `MaybeUninit` until `seq` confirms it was not.
+        // the volatile read can still overlap a write,
+        // which is a data race under the Rust memory model
+        // even though the result of such a read is always discarded.
+        let copy =
+            unsafe { ptr::read_volatile(lock.pot.get().cast::<MaybeUninit<FlowerPot<T, N>>>()) };
+
+        atomic::fence(Ordering::Acquire);
+
+        if lock.seq.load(Ordering::Relaxed) != seq {
+            return None;
+        }
+
+        // SAFETY: `seq` did not change and was even, so no write overlapped the copy
+        // and it holds the pot exactly as the last write left it.
+        // a torn copy is never returned, the read is retried instead.
+        Some(unsafe { copy.assume_init() })
+    }
+}
+
+impl<T: Copy, const N: usize> Clone for SeqLockReader<'_, T, N> {
+    fn clone(&self) -> Self {
+        Self { lock: self.lock }
+    }
+}
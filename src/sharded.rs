@@ -0,0 +1,89 @@
+use crate::{FlowerPot, invalid_input};
+use std::hash::{BuildHasher, RandomState};
+use std::io::Result;
+use std::sync::{Mutex, MutexGuard, PoisonError};
+use std::thread;
+
+/// Shard padded to its own cache line,
+/// so threads pushing to neighboring shards do not contend.
+#[repr(align(64))]
+struct Shard<T, const N: usize>(Mutex<FlowerPot<T, N>>);
+
+/// Collection of `SHARDS` pots of `N` items each,
+/// where every thread or core pushes to a shard of its own.
+pub struct ShardedPots<T, const N: usize, const SHARDS: usize> {
+    shards: [Shard<T, N>; SHARDS],
+    hasher: RandomState,
+}
+
+impl<T, const N: usize, const SHARDS: usize> ShardedPots<T, N, SHARDS> {
+    /// Creates a new `ShardedPots` with every shard empty.
+    pub fn new() -> ShardedPots<T, N, SHARDS> {
+        const { assert!(SHARDS > 0, "there must be at least one shard") };
+
+        Self {
+            shards: std::array::from_fn(|_| Shard(Mutex::new(FlowerPot::new()))),
+            hasher: RandomState::new(),
+        }
+    }
+
+    /// Pushes an item to the shard of the current thread.
+    /// returns `Err` if that shard is full.
+    pub fn push(&self, item: T) -> Result<()> {
+        let shard = self.hasher.hash_one(thread::current().id()) as usize % SHARDS;
+
+        self.lock(shard).push(item)
+    }
+
+    /// Pushes an item to an specified shard, such as the one of the current core.
+    /// returns `Err` with `InvalidInput` if that shard does not exist
+    /// and `StorageFull` if it is full.
+    pub fn push_to(&self, shard: usize, item: T) -> Result<()> {
+        if shard >= SHARDS {
+            let err = invalid_input();
+
+            return Err(err);
+        }
+
+        self.lock(shard).push(item)
+    }
+
+    /// Returns the amount of items in every shard combined.
+    pub fn len(&self) -> usize {
+        (0..SHARDS).map(|shard| self.lock(shard).len()).sum()
+    }
+
+    /// Returns `true` if every shard is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Moves the items of every shard, shard by shard, into a single `FlowerPot`.
+    /// fails to compile if `M` is smaller than `N * SHARDS`.
+    pub fn drain_all<const M: usize>(&self) -> FlowerPot<T, M> {
+        const { assert!(M >= N * SHARDS, "capacity is smaller than required") };
+
+        let mut all = FlowerPot::new();
+
+        for shard in 0..SHARDS {
+            // Can not fail, `M` is big enough for every item of every shard.
+            let _ = all.push_iter(self.lock(shard).recycle());
+        }
+
+        all
+    }
+
+    fn lock(&self, shard: usize) -> MutexGuard<'_, FlowerPot<T, N>> {
+        // A panic while holding the lock can not leave the pot inconsistent.
+        self.shards[shard]
+            .0
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<T, const N: usize, const SHARDS: usize> Default for ShardedPots<T, N, SHARDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
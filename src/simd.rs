@@ -0,0 +1,123 @@
+use crate::FlowerPot;
+
+/// Amount of items processed per step,
+/// the loops are written lane-wise so the compiler can vectorize them.
+const LANES: usize = 16;
+
+/// Smallest capacity for which the lane-wise loops are used,
+/// smaller pots fall back to the scalar ones.
+const MIN_CAPACITY: usize = 64;
+
+/// Returns `true` if the lane-wise loops should be used for a capacity of `N`,
+/// they are only compiled in with the `simd` feature.
+const fn lane_wise<const N: usize>() -> bool {
+    cfg!(feature = "simd") && N >= MIN_CAPACITY
+}
+
+macro_rules! simd_ops {
+    ($($num:ty, $zero:expr, $add:expr;)*) => {
+        $(
+            impl<const N: usize> FlowerPot<$num, N> {
+                /// Returns `true` if any item is equal to `value`.
+                pub fn contains(&self, value: $num) -> bool {
+                    let items = self.get_init_slice();
+
+                    if !lane_wise::<N>() {
+                        return items.contains(&value);
+                    }
+
+                    let chunks = items.chunks_exact(LANES);
+                    let rest = chunks.remainder();
+
+                    chunks
+                        .into_iter()
+                        .any(|chunk| chunk.iter().fold(false, |found, &item| found | (item == value)))
+                        || rest.contains(&value)
+                }
+
+                /// Appends copies of `value` until the `FlowerPot` is full.
+                /// unlike `fill` on slices, the items already in it are kept as they are.
+                pub fn fill(&mut self, value: $num) {
+                    self.push_iter(std::iter::repeat_n(value, N - self.len()));
+                }
+
+                /// Adds up every item, wrapping around on integer overflow.
+                /// the order of the additions is unspecified, which can affect float rounding.
+                pub fn sum(&self) -> $num {
+                    let add = $add;
+                    let items = self.get_init_slice();
+
+                    if !lane_wise::<N>() {
+                        return items.iter().fold($zero, |sum, &item| add(sum, item));
+                    }
+
+                    let chunks = items.chunks_exact(LANES);
+                    let rest = chunks.remainder();
+                    let mut lanes = [$zero; LANES];
+
+                    for chunk in chunks {
+                        for (lane, &item) in lanes.iter_mut().zip(chunk) {
+                            *lane = add(*lane, item);
+                        }
+                    }
+
+                    lanes
+                        .iter()
+                        .chain(rest)
+                        .fold($zero, |sum, &item| add(sum, item))
+                }
+
+                /// Returns `true` if the items are equal to the ones of `other`.
+                pub fn eq_slice(&self, other: &[$num]) -> bool {
+                    let items = self.get_init_slice();
+
+                    if !lane_wise::<N>() || items.len() != other.len() {
+                        return items == other;
+                    }
+
+                    let chunks = items.chunks_exact(LANES).zip(other.chunks_exact(LANES));
+                    let rest = items.len() - items.len() % LANES;
+
+                    chunks.into_iter().all(|(a, b)| {
+                        a.iter().zip(b).fold(true, |equal, (a, b)| equal & (a == b))
+                    }) && items[rest..] == other[rest..]
+                }
+            }
+        )*
+    };
+}
+
+simd_ops! {
+    u8, 0, u8::wrapping_add;
+    u16, 0, u16::wrapping_add;
+    u32, 0, u32::wrapping_add;
+    f32, 0.0, |a: f32, b: f32| a + b;
+}
+
+macro_rules! simd_position {
+    ($($num:ty),*) => {
+        $(
+            impl<const N: usize> FlowerPot<$num, N> {
+                /// Returns the index of the first item equal to `value`.
+                /// returns `None` if it is not found.
+                pub fn position(&self, value: $num) -> Option<usize> {
+                    let items = self.get_init_slice();
+
+                    if !lane_wise::<N>() {
+                        return items.iter().position(|&item| item == value);
+                    }
+
+                    let start = items
+                        .chunks(LANES)
+                        .position(|chunk| chunk.iter().fold(false, |found, &item| found | (item == value)))?
+                        * LANES;
+
+                    items[start..].iter().position(|&item| item == value).map(|index| start + index)
+                }
+            }
+        )*
+    };
+}
+
+// `FlowerPot<u8, N>::position` is always available, scanning a whole word at a time.
+simd_position!(u16, u32, f32);
@@ -0,0 +1,68 @@
+//! Serial Line Internet Protocol framing.
+//!
+//! Encoded frames are terminated by an `END` byte,
+//! `END` and `ESC` bytes inside of them are escaped.
+
+use crate::{FlowerPot, invalid_data};
+use std::io::Result;
+
+/// Byte terminating a frame.
+pub const END: u8 = 0xC0;
+/// Byte starting an escape sequence.
+pub const ESC: u8 = 0xDB;
+/// Escaped form of `END`.
+pub const ESC_END: u8 = 0xDC;
+/// Escaped form of `ESC`.
+pub const ESC_ESC: u8 = 0xDD;
+
+/// Encodes `src` and appends it to `dst`, followed by `END`.
+/// returns the amount of bytes appended,
+/// or `Err` with `StorageFull` if they do not fit, in which case nothing is appended.
+pub fn encode<const N: usize>(src: &[u8], dst: &mut FlowerPot<u8, N>) -> Result<usize> {
+    let start = dst.len();
+
+    let result = src
+        .iter()
+        .try_for_each(|&byte| match byte {
+            END => dst.put_slice(&[ESC, ESC_END]),
+            ESC => dst.put_slice(&[ESC, ESC_ESC]),
+            byte => dst.push(byte),
+        })
+        .and_then(|_| dst.push(END));
+
+    result.inspect_err(|_| dst.truncate(start))?;
+
+    Ok(dst.len() - start)
+}
+
+/// Decodes the frame `src` and appends it to `dst`,
+/// decoding stops at the first `END` byte after the frame data.
+/// returns the amount of bytes appended, or `Err` with `InvalidData` if the frame is malformed
+/// and `StorageFull` if the bytes do not fit, in both cases nothing is appended.
+pub fn decode<const N: usize>(src: &[u8], dst: &mut FlowerPot<u8, N>) -> Result<usize> {
+    let start = dst.len();
+
+    // Leading `END` bytes only flush line noise.
+    let skip = src.iter().take_while(|&&byte| byte == END).count();
+    let mut bytes = src[skip..].iter();
+
+    let result = loop {
+        let byte = match bytes.next() {
+            None | Some(&END) => break Ok(()),
+            Some(&ESC) => match bytes.next() {
+                Some(&ESC_END) => END,
+                Some(&ESC_ESC) => ESC,
+                _ => break Err(invalid_data()),
+            },
+            Some(&byte) => byte,
+        };
+
+        if let Err(err) = dst.push(byte) {
+            break Err(err);
+        }
+    };
+
+    result.inspect_err(|_| dst.truncate(start))?;
+
+    Ok(dst.len() - start)
+}
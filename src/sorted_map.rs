@@ -0,0 +1,174 @@
+use crate::FlowerPot;
+use std::borrow::Borrow;
+use std::ops::{Bound, RangeBounds};
+
+/// Map stored in a `FlowerPot` of key-value pairs sorted by key,
+/// can store up to `N` entries.
+/// lookups use binary search, so larger `N` stay fast.
+#[derive(Debug, Default)]
+pub struct FlowerSortedMap<K, V, const N: usize> {
+    entries: FlowerPot<(K, V), N>,
+}
+
+impl<K: Ord, V, const N: usize> FlowerSortedMap<K, V, N> {
+    /// Creates a new empty `FlowerSortedMap`.
+    pub fn new() -> FlowerSortedMap<K, V, N> {
+        Self {
+            entries: FlowerPot::new(),
+        }
+    }
+
+    /// Returns the amount of entries in the map.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map holds no entries.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns `true` if the map can not hold any more entries.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.entries.is_full()
+    }
+
+    /// Returns the total amount of entries the map can hold,
+    /// which is always `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    fn search<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.entries
+            .get_init_slice()
+            .binary_search_by(|(k, _)| k.borrow().cmp(key))
+    }
+
+    /// Returns `true` if the map holds an entry for `key`.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(key).is_ok()
+    }
+
+    /// Obtains an immutable reference to the value of `key`.
+    /// returns `None` if the map holds no such entry.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+
+        self.entries.get(index).map(|(_, v)| v)
+    }
+
+    /// Obtains a mutable reference to the value of `key`.
+    /// returns `None` if the map holds no such entry.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+
+        self.entries.get_mut(index).map(|(_, v)| v)
+    }
+
+    /// Inserts a value for `key`, returning the previous value if there was one.
+    /// returns `Err` with `StorageFull` if `key` is new and the map is full.
+    pub fn insert(&mut self, key: K, value: V) -> std::io::Result<Option<V>> {
+        match self.search(&key) {
+            Ok(index) => Ok(self.entries.replace(index, (key, value)).map(|(_, v)| v)),
+            Err(index) => self.entries.insert(index, (key, value)).map(|_| None),
+        }
+    }
+
+    /// Removes the entry of `key` and returns its value,
+    /// keeping the remaining entries sorted.
+    /// returns `None` if the map holds no such entry.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+
+        self.entries.remove(index).map(|(_, v)| v)
+    }
+
+    /// Returns the entry with the smallest key.
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.entries.get_init_slice().first().map(|(k, v)| (k, v))
+    }
+
+    /// Returns the entry with the biggest key.
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.entries.get_init_slice().last().map(|(k, v)| (k, v))
+    }
+
+    /// Returns an iterator over the entries whose keys lie within `range`,
+    /// in ascending order of keys.
+    pub fn range<Q, R>(&self, range: R) -> impl DoubleEndedIterator<Item = (&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let entries = self.entries.get_init_slice();
+
+        let start = match range.start_bound() {
+            Bound::Included(key) => entries.partition_point(|(k, _)| k.borrow() < key),
+            Bound::Excluded(key) => entries.partition_point(|(k, _)| k.borrow() <= key),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(key) => entries.partition_point(|(k, _)| k.borrow() <= key),
+            Bound::Excluded(key) => entries.partition_point(|(k, _)| k.borrow() < key),
+            Bound::Unbounded => entries.len(),
+        };
+
+        entries[start..end.max(start)].iter().map(|(k, v)| (k, v))
+    }
+
+    /// Drops every entry in the map.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns an iterator over the entries in ascending order of keys.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (&K, &V)> {
+        self.entries.get_init_slice().iter().map(|(k, v)| (k, v))
+    }
+
+    /// Returns an iterator over the entries in ascending order of keys,
+    /// with mutable references to the values.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (&K, &mut V)> {
+        self.entries
+            .get_init_slice_mut()
+            .iter_mut()
+            .map(|(k, v)| (&*k, v))
+    }
+
+    /// Returns an iterator over the keys in ascending order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> {
+        self.iter().map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over the values in ascending order of keys.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> {
+        self.iter().map(|(_, v)| v)
+    }
+}
@@ -0,0 +1,181 @@
+use crate::{invalid_input, storage_full};
+use std::io::Result;
+use std::mem::MaybeUninit;
+
+/// Slot-addressed storage of up to `N` elements,
+/// where any index can be occupied or free.
+/// the occupied indexes are tracked by an inline bitmap of `WORDS` `u64`s,
+/// which must hold at least `N` bits, so `N` above 64 needs a bigger `WORDS`.
+pub struct SparseFlowerPot<T, const N: usize, const WORDS: usize = 1> {
+    items: [MaybeUninit<T>; N],
+    occupied: [u64; WORDS],
+}
+
+impl<T, const N: usize, const WORDS: usize> SparseFlowerPot<T, N, WORDS> {
+    const FITS: () = assert!(WORDS * 64 >= N, "bitmap is smaller than the capacity");
+
+    /// Creates a new `SparseFlowerPot` with every index free.
+    pub fn new() -> SparseFlowerPot<T, N, WORDS> {
+        let () = Self::FITS;
+
+        Self {
+            items: [const { MaybeUninit::uninit() }; N],
+            occupied: [0; WORDS],
+        }
+    }
+
+    /// Returns the amount of occupied indexes.
+    pub fn len(&self) -> usize {
+        self.occupied
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns `true` if every index is free.
+    pub fn is_empty(&self) -> bool {
+        self.occupied.iter().all(|word| *word == 0)
+    }
+
+    /// Returns the total amount of indexes, which is always `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if `index` is occupied.
+    #[inline]
+    pub fn contains(&self, index: usize) -> bool {
+        index < N && self.occupied[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Stores `item` at `index` and returns the item that was there before.
+    /// returns `Err` with `InvalidInput` if `index` is not below `N`.
+    pub fn set(&mut self, index: usize, item: T) -> Result<Option<T>> {
+        if index >= N {
+            let err = invalid_input();
+
+            return Err(err);
+        }
+
+        let old = self.take(index);
+
+        self.items[index].write(item);
+        self.occupied[index / 64] |= 1 << (index % 64);
+
+        Ok(old)
+    }
+
+    /// Stores `item` at the lowest free index.
+    /// returns that index, or `Err` with `StorageFull` if every index is occupied.
+    pub fn insert(&mut self, item: T) -> Result<usize> {
+        let Some(index) = self.first_free() else {
+            let err = storage_full();
+
+            return Err(err);
+        };
+
+        self.set(index, item)?;
+
+        Ok(index)
+    }
+
+    /// Takes the item at `index` out, freeing the index.
+    /// returns `None` if it is free or out of bounds.
+    pub fn take(&mut self, index: usize) -> Option<T> {
+        if !self.contains(index) {
+            return None;
+        }
+
+        self.occupied[index / 64] &= !(1 << (index % 64));
+
+        // SAFETY: the index was occupied, so the item is initialized,
+        // it is no longer marked so it is read exactly once.
+        Some(unsafe { self.items[index].assume_init_read() })
+    }
+
+    /// Obtains an immutable reference to the item at `index`.
+    /// returns `None` if it is free or out of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        // SAFETY: the index is occupied, so the item is initialized.
+        self.contains(index)
+            .then(|| unsafe { self.items[index].assume_init_ref() })
+    }
+
+    /// Obtains a mutable reference to the item at `index`.
+    /// returns `None` if it is free or out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if !self.contains(index) {
+            return None;
+        }
+
+        // SAFETY: the index is occupied, so the item is initialized.
+        Some(unsafe { self.items[index].assume_init_mut() })
+    }
+
+    /// Returns the lowest free index.
+    /// returns `None` if every index is occupied.
+    pub fn first_free(&self) -> Option<usize> {
+        self.occupied
+            .iter()
+            .enumerate()
+            .find(|(_, word)| **word != u64::MAX)
+            .map(|(word, bits)| word * 64 + bits.trailing_ones() as usize)
+            .filter(|index| *index < N)
+    }
+
+    /// Iterates over the occupied indexes and their items in order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        // SAFETY: only occupied indexes are yielded, so the items are initialized.
+        self.indexes()
+            .map(|index| (index, unsafe { self.items[index].assume_init_ref() }))
+    }
+
+    /// Iterates mutably over the occupied indexes and their items in order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, &mut T)> {
+        let occupied = self.occupied;
+
+        self.items
+            .iter_mut()
+            .enumerate()
+            .filter(move |(index, _)| occupied[index / 64] & (1 << (index % 64)) != 0)
+            // SAFETY: only occupied indexes are yielded, so the items are initialized.
+            .map(|(index, item)| (index, unsafe { item.assume_init_mut() }))
+    }
+
+    /// Drops every item, freeing every index.
+    pub fn clear(&mut self) {
+        for index in 0..N {
+            drop(self.take(index));
+        }
+    }
+
+    fn indexes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.occupied.iter().enumerate().flat_map(|(word, &bits)| {
+            let mut bits = bits;
+
+            std::iter::from_fn(move || {
+                if bits == 0 {
+                    return None;
+                }
+
+                let bit = bits.trailing_zeros() as usize;
+                bits &= bits - 1;
+
+                Some(word * 64 + bit)
+            })
+        })
+    }
+}
+
+impl<T, const N: usize, const WORDS: usize> Default for SparseFlowerPot<T, N, WORDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize, const WORDS: usize> Drop for SparseFlowerPot<T, N, WORDS> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
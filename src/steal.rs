@@ -0,0 +1,222 @@
+use crate::assert::wrap;
+use crate::{CapacityAssert, storage_full};
+use std::cell::UnsafeCell;
+use std::io::Result;
+use std::mem::MaybeUninit;
+use std::sync::atomic::{self, AtomicIsize, Ordering};
+
+/// Bounded work-stealing deque
+/// holding up to `N` elements of inline storage.
+/// The owning worker pushes and pops at the bottom,
+/// while any amount of stealers take elements from the top.
+/// indexes wrap around with a mask if `N` is a power of two,
+/// which `assert_capacity_power_of_two` can enforce.
+pub struct FlowerWorkDeque<T, const N: usize> {
+    items: [UnsafeCell<MaybeUninit<T>>; N],
+    top: AtomicIsize,
+    bottom: AtomicIsize,
+}
+
+// SAFETY: Elements are only ever moved out by the handle
+// that won the race for their index.
+unsafe impl<T: Send, const N: usize> Sync for FlowerWorkDeque<T, N> {}
+
+impl<T, const N: usize> FlowerWorkDeque<T, N> {
+    /// Creates a new empty `FlowerWorkDeque`.
+    pub fn new() -> FlowerWorkDeque<T, N> {
+        let items = [const { UnsafeCell::new(MaybeUninit::uninit()) }; N];
+
+        Self {
+            items,
+            top: AtomicIsize::new(0),
+            bottom: AtomicIsize::new(0),
+        }
+    }
+
+    /// Returns the amount of elements currently in the deque.
+    /// this is only a snapshot while stealers are active.
+    pub fn len(&self) -> usize {
+        let bottom = self.bottom.load(Ordering::Acquire);
+        let top = self.top.load(Ordering::Acquire);
+
+        (bottom - top).max(0) as usize
+    }
+
+    /// Returns `true` if the deque holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Splits the deque into the owning worker
+    /// and a stealer that can be cloned and shared between threads.
+    pub fn split(&mut self) -> (DequeWorker<'_, T, N>, DequeStealer<'_, T, N>) {
+        let deque = &*self;
+
+        (DequeWorker { deque }, DequeStealer { deque })
+    }
+
+    /// Fails to compile if `N` is not a power of two.
+    #[inline]
+    pub const fn assert_capacity_power_of_two() {
+        CapacityAssert::<N, 0>::POWER_OF_TWO
+    }
+
+    fn slot(&self, index: isize) -> *mut MaybeUninit<T> {
+        self.items[wrap::<N>(index as usize)].get()
+    }
+}
+
+impl<T, const N: usize> Default for FlowerWorkDeque<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for FlowerWorkDeque<T, N> {
+    fn drop(&mut self) {
+        let top = *self.top.get_mut();
+        let bottom = *self.bottom.get_mut();
+
+        for index in top..bottom {
+            // SAFETY: every index between `top` and `bottom`
+            // holds an element that was not taken by anyone.
+            unsafe { (*self.slot(index)).assume_init_drop() }
+        }
+    }
+}
+
+/// Owning half of a `FlowerWorkDeque`.
+pub struct DequeWorker<'a, T, const N: usize> {
+    deque: &'a FlowerWorkDeque<T, N>,
+}
+
+impl<T, const N: usize> DequeWorker<'_, T, N> {
+    /// Pushes an item to the bottom of the deque.
+    /// returns `Ok` if the operation was successful.
+    /// if the deque is full, returns `Err`
+    pub fn push(&mut self, item: T) -> Result<()> {
+        let deque = self.deque;
+        let bottom = deque.bottom.load(Ordering::Relaxed);
+        let top = deque.top.load(Ordering::Acquire);
+
+        if bottom - top >= N as isize {
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        // SAFETY: the slot at `bottom` is outside of the range
+        // stealers are allowed to read from.
+        unsafe { (*deque.slot(bottom)).write(item) };
+
+        deque.bottom.store(bottom + 1, Ordering::Release);
+
+        Ok(())
+    }
+
+    /// Pops the most recently pushed item from the bottom of the deque.
+    /// returns `None` if the deque is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let deque = self.deque;
+        let bottom = deque.bottom.load(Ordering::Relaxed) - 1;
+
+        deque.bottom.store(bottom, Ordering::Relaxed);
+        atomic::fence(Ordering::SeqCst);
+
+        let top = deque.top.load(Ordering::Relaxed);
+
+        if top > bottom {
+            deque.bottom.store(bottom + 1, Ordering::Relaxed);
+
+            return None;
+        }
+
+        // SAFETY: `top <= bottom`, so the slot holds an element.
+        // if a stealer wins the race for it below, we never assume it initialized.
+        let item = unsafe { deque.slot(bottom).read() };
+
+        if top == bottom {
+            // This is the last element, race the stealers for it.
+            let won = deque
+                .top
+                .compare_exchange(top, top + 1, Ordering::SeqCst, Ordering::Relaxed)
+                .is_ok();
+
+            deque.bottom.store(bottom + 1, Ordering::Relaxed);
+
+            if !won {
+                return None;
+            }
+        }
+
+        // SAFETY: we own the element at this index.
+        Some(unsafe { item.assume_init() })
+    }
+
+    /// Returns the amount of elements currently in the deque.
+    pub fn len(&self) -> usize {
+        self.deque.len()
+    }
+
+    /// Returns `true` if the deque holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+}
+
+/// Stealing half of a `FlowerWorkDeque`.
+pub struct DequeStealer<'a, T, const N: usize> {
+    deque: &'a FlowerWorkDeque<T, N>,
+}
+
+impl<T, const N: usize> DequeStealer<'_, T, N> {
+    /// Steals the oldest item from the top of the deque.
+    /// retries on contention with other stealers
+    /// and returns `None` if the deque is empty.
+    pub fn steal(&self) -> Option<T> {
+        let deque = self.deque;
+
+        loop {
+            let top = deque.top.load(Ordering::Acquire);
+            atomic::fence(Ordering::SeqCst);
+            let bottom = deque.bottom.load(Ordering::Acquire);
+
+            if top >= bottom {
+                return None;
+            }
+
+            // SAFETY: `top < bottom`, so the slot held an element when it was loaded.
+            // the copy stays in a `MaybeUninit` and is only assumed initialized
+            // once the CAS below proves nobody else took the slot.
+            // if the owner wrapped around and is overwriting the slot meanwhile,
+            // the volatile read still overlaps that write, which is a data race
+            // under the Rust memory model even though its result is discarded.
+            let item = unsafe { deque.slot(top).read_volatile() };
+
+            if deque
+                .top
+                .compare_exchange(top, top + 1, Ordering::SeqCst, Ordering::Relaxed)
+                .is_ok()
+            {
+                // SAFETY: we own the element at this index.
+                return Some(unsafe { item.assume_init() });
+            }
+        }
+    }
+
+    /// Returns the amount of elements currently in the deque.
+    pub fn len(&self) -> usize {
+        self.deque.len()
+    }
+
+    /// Returns `true` if the deque holds no elements.
+    pub fn is_empty(&self) -> bool {
+        self.deque.is_empty()
+    }
+}
+
+impl<T, const N: usize> Clone for DequeStealer<'_, T, N> {
+    fn clone(&self) -> Self {
+        Self { deque: self.deque }
+    }
+}
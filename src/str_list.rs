@@ -0,0 +1,92 @@
+use crate::{FlowerPot, FlowerString, storage_full};
+use std::io::Result;
+
+/// List of strings packed into one shared inline arena,
+/// can store up to `ITEMS` strings taking up to `BYTES` bytes in total.
+#[derive(Debug, Default)]
+pub struct FlowerStrList<const BYTES: usize, const ITEMS: usize> {
+    arena: FlowerString<BYTES>,
+    /// End offset of every string in `arena`.
+    ends: FlowerPot<usize, ITEMS>,
+}
+
+impl<const BYTES: usize, const ITEMS: usize> FlowerStrList<BYTES, ITEMS> {
+    /// Creates a new empty `FlowerStrList`.
+    pub fn new() -> FlowerStrList<BYTES, ITEMS> {
+        Self {
+            arena: FlowerString::new(),
+            ends: FlowerPot::new(),
+        }
+    }
+
+    /// Returns the amount of strings in the list.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.ends.len()
+    }
+
+    /// Returns `true` if the list holds no strings.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.ends.is_empty()
+    }
+
+    /// Returns the amount of arena bytes still available.
+    #[inline]
+    pub const fn remaining_bytes(&self) -> usize {
+        self.arena.remaining_capacity()
+    }
+
+    /// Appends a string to the list.
+    /// returns `Err` with `StorageFull` if either the arena or the index table
+    /// has no room left, in which case nothing is appended.
+    pub fn push_str(&mut self, s: &str) -> Result<()> {
+        if self.ends.is_full() {
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        self.arena.push_str(s)?;
+        self.ends.push(self.arena.len())
+    }
+
+    /// Obtains the string at an specified index.
+    /// returns `None` if that index is out of bounds.
+    pub fn get(&self, index: usize) -> Option<&str> {
+        let end = *self.ends.get(index)?;
+        let start = self.start_of(index);
+
+        Some(&self.arena[start..end])
+    }
+
+    /// Removes the last string from the list.
+    /// returns `false` if the list is empty.
+    pub fn pop(&mut self) -> bool {
+        if self.ends.pop().is_none() {
+            return false;
+        }
+
+        self.arena.truncate(self.start_of(self.len()));
+
+        true
+    }
+
+    /// Drops every string in the list.
+    pub fn clear(&mut self) {
+        self.arena.clear();
+        self.ends.clear();
+    }
+
+    /// Returns an iterator over the strings in the list.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        (0..self.len()).map(|index| self.get(index).unwrap_or_default())
+    }
+
+    fn start_of(&self, index: usize) -> usize {
+        match index.checked_sub(1) {
+            Some(prev) => self.ends[prev],
+            None => 0,
+        }
+    }
+}
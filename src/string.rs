@@ -0,0 +1,210 @@
+use crate::{FlowerPot, invalid_data, storage_full};
+use std::fmt::{self, Write};
+use std::io::{self, Result};
+use std::ops::Deref;
+use std::str::FromStr;
+
+#[derive(Default)]
+/// UTF-8 string stored in a `FlowerPot`
+/// holding up to `N` bytes.
+pub struct FlowerString<const N: usize> {
+    bytes: FlowerPot<u8, N>,
+}
+
+impl<const N: usize> FlowerString<N> {
+    /// Creates a new empty `FlowerString`.
+    pub fn new() -> FlowerString<N> {
+        Self {
+            bytes: FlowerPot::new(),
+        }
+    }
+
+    /// Returns the length of the string in bytes.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.bytes.len()
+    }
+
+    /// Returns `true` if the string is empty.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    /// Returns the total amount of bytes the string can hold,
+    /// which is always `N`.
+    #[inline]
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Returns the amount of bytes that can still be pushed.
+    #[inline]
+    pub const fn remaining_capacity(&self) -> usize {
+        self.bytes.remaining_capacity()
+    }
+
+    /// Appends `s` to the string.
+    /// returns `Err` with `StorageFull` if it does not fit, in which case nothing is appended.
+    pub fn push_str(&mut self, s: &str) -> Result<()> {
+        self.bytes.put_slice(s.as_bytes())
+    }
+
+    /// Appends `c` to the string.
+    /// returns `Err` with `StorageFull` if it does not fit.
+    pub fn push(&mut self, c: char) -> Result<()> {
+        self.push_str(c.encode_utf8(&mut [0; 4]))
+    }
+
+    /// Removes the last character and returns it.
+    /// returns `None` if the string is empty.
+    pub fn pop(&mut self) -> Option<char> {
+        let c = self.as_str().chars().next_back()?;
+        self.bytes.truncate(self.len() - c.len_utf8());
+
+        Some(c)
+    }
+
+    /// Shortens the string to `len` bytes,
+    /// does nothing if `len` is bigger than or equal to the length.
+    /// panics if `len` does not lie on a character boundary.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len() {
+            assert!(
+                self.as_str().is_char_boundary(len),
+                "`FlowerString::truncate` must be called on a char boundary"
+            );
+
+            self.bytes.truncate(len);
+        }
+    }
+
+    /// Removes the whole contents of the string.
+    pub fn clear(&mut self) {
+        self.bytes.clear();
+    }
+
+    /// Obtains the contents as an `&str`.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The bytes are only ever appended from whole `str`s
+        // and truncated on character boundaries.
+        unsafe { std::str::from_utf8_unchecked(self.bytes.get_init_slice()) }
+    }
+
+    /// Obtains the underlying `FlowerPot` of bytes.
+    pub fn as_pot(&self) -> &FlowerPot<u8, N> {
+        &self.bytes
+    }
+}
+
+impl<const N: usize> Deref for FlowerString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for FlowerString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Display for FlowerString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for FlowerString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Write for FlowerString<N> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.push_str(s).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const N: usize> PartialEq for FlowerString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> PartialEq<str> for FlowerString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for FlowerString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> Eq for FlowerString<N> {}
+
+impl<const N: usize> FromStr for FlowerString<N> {
+    type Err = io::Error;
+
+    /// Copies `s` into a new `FlowerString`.
+    /// returns `Err` with `StorageFull` if it is longer than `N` bytes.
+    fn from_str(s: &str) -> Result<FlowerString<N>> {
+        let mut string = Self::new();
+        string.push_str(s)?;
+
+        Ok(string)
+    }
+}
+
+impl<T: FromStr, const N: usize> FlowerPot<T, N> {
+    /// Parses every `sep` separated part of `s` into a new `FlowerPot`.
+    /// returns `Err` with `InvalidData` if a part fails to parse
+    /// and `StorageFull` if there are more than `N` parts.
+    pub fn parse_split(s: &str, sep: &str) -> Result<FlowerPot<T, N>> {
+        let mut pot = Self::new();
+
+        for part in s.split(sep) {
+            let item = part.parse().map_err(|_| invalid_data())?;
+
+            pot.push(item)?;
+        }
+
+        Ok(pot)
+    }
+}
+
+impl<T: fmt::Display, const N: usize> FlowerPot<T, N> {
+    /// Appends every item formatted with `Display` to `dst`, separated by `sep`.
+    /// returns `Err` with `StorageFull` if it does not fit, in which case nothing is appended.
+    pub fn join_into<const M: usize>(&self, dst: &mut FlowerString<M>, sep: &str) -> Result<()> {
+        let start = dst.len();
+
+        for (index, item) in self.get_init_slice().iter().enumerate() {
+            let sep = if index == 0 { "" } else { sep };
+
+            if write!(dst, "{sep}{item}").is_err() {
+                dst.truncate(start);
+                let err = storage_full();
+
+                return Err(err);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Formats every item with `Display` into a new `FlowerString`, separated by `sep`.
+    /// returns `Err` with `StorageFull` if the output is longer than `M` bytes.
+    pub fn to_delimited<const M: usize>(&self, sep: &str) -> Result<FlowerString<M>> {
+        let mut string = FlowerString::new();
+        self.join_into(&mut string, sep)?;
+
+        Ok(string)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{FlowerMinMaxHeap, FlowerPot};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Keep {
+    Largest,
+    Smallest,
+}
+
+/// Selects the `K` largest or smallest items
+/// out of an unbounded stream of offered items.
+#[derive(Debug)]
+pub struct TopK<T, const K: usize> {
+    heap: FlowerMinMaxHeap<T, K>,
+    keep: Keep,
+}
+
+impl<T: Ord, const K: usize> TopK<T, K> {
+    /// Creates a new `TopK` keeping the `K` largest items.
+    pub fn largest() -> TopK<T, K> {
+        Self {
+            heap: FlowerMinMaxHeap::new(),
+            keep: Keep::Largest,
+        }
+    }
+
+    /// Creates a new `TopK` keeping the `K` smallest items.
+    pub fn smallest() -> TopK<T, K> {
+        Self {
+            heap: FlowerMinMaxHeap::new(),
+            keep: Keep::Smallest,
+        }
+    }
+
+    /// Returns the amount of items kept so far.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.heap.len()
+    }
+
+    /// Returns `true` if no items are kept.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.heap.is_empty()
+    }
+
+    /// Offers an item to the selection.
+    /// returns the item that is no longer kept, which is either
+    /// the offered item itself or the one it displaced.
+    pub fn offer(&mut self, item: T) -> Option<T> {
+        if !self.heap.is_full() {
+            // The heap is not full, so this can not fail.
+            let _ = self.heap.push(item);
+
+            return None;
+        }
+
+        let worst = match self.keep {
+            Keep::Largest => self.heap.peek_min(),
+            Keep::Smallest => self.heap.peek_max(),
+        };
+
+        let better = match (worst, self.keep) {
+            (None, _) => false,
+            (Some(worst), Keep::Largest) => item > *worst,
+            (Some(worst), Keep::Smallest) => item < *worst,
+        };
+
+        if !better {
+            return Some(item);
+        }
+
+        let evicted = match self.keep {
+            Keep::Largest => self.heap.pop_min(),
+            Keep::Smallest => self.heap.pop_max(),
+        };
+
+        let _ = self.heap.push(item);
+
+        evicted
+    }
+
+    /// Obtains an immutable reference to the worst item that is still kept,
+    /// which is the next one to be displaced.
+    pub fn threshold(&self) -> Option<&T> {
+        match self.keep {
+            Keep::Largest => self.heap.peek_min(),
+            Keep::Smallest => self.heap.peek_max(),
+        }
+    }
+
+    /// Consumes the selection and returns the kept items
+    /// sorted from best to worst.
+    pub fn into_sorted(self) -> FlowerPot<T, K> {
+        let mut sorted = self.heap.into_sorted();
+
+        if self.keep == Keep::Largest {
+            sorted.get_init_slice_mut().reverse();
+        }
+
+        sorted
+    }
+}
+
+impl<T: Ord, const K: usize> Extend<T> for TopK<T, K> {
+    /// Offers every item of `iter`.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.offer(item);
+        }
+    }
+}
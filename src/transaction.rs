@@ -0,0 +1,44 @@
+use crate::FlowerPot;
+use std::io::Result;
+
+/// Guard returned by `FlowerPot::transaction`.
+/// items pushed through it are staged in the pot
+/// and dropped again if the guard is dropped without `commit`.
+pub struct Transaction<'a, T, const N: usize> {
+    pot: &'a mut FlowerPot<T, N>,
+    start: usize,
+}
+
+impl<'a, T, const N: usize> Transaction<'a, T, N> {
+    pub(crate) fn new(pot: &'a mut FlowerPot<T, N>) -> Transaction<'a, T, N> {
+        let start = pot.len();
+
+        Self { pot, start }
+    }
+
+    /// Stages an item in the `FlowerPot`.
+    /// returns `Err` if the pot is full.
+    pub fn push(&mut self, item: T) -> Result<()> {
+        self.pot.push(item)
+    }
+
+    /// Obtains an immutable reference to the staged items.
+    pub fn staged(&self) -> &[T] {
+        &self.pot.get_init_slice()[self.start..]
+    }
+
+    /// Keeps every staged item in the `FlowerPot`.
+    pub fn commit(mut self) {
+        self.start = self.pot.len();
+    }
+
+    /// Drops every staged item,
+    /// same as dropping the guard.
+    pub fn rollback(self) {}
+}
+
+impl<T, const N: usize> Drop for Transaction<'_, T, N> {
+    fn drop(&mut self) {
+        self.pot.truncate(self.start);
+    }
+}
@@ -0,0 +1,53 @@
+use crate::{CollectError, FlowerPot};
+use std::fs::File;
+use std::io;
+use std::net::{TcpListener, TcpStream, UdpSocket};
+
+/// Duplication that can fail, such as for OS handles.
+pub trait TryClone: Sized {
+    /// Error returned when duplication fails.
+    type Error;
+
+    /// Creates a duplicate of `self`.
+    fn try_clone(&self) -> Result<Self, Self::Error>;
+}
+
+macro_rules! io_try_clone {
+    ($($handle:ty),*) => {
+        $(
+            impl TryClone for $handle {
+                type Error = io::Error;
+
+                fn try_clone(&self) -> io::Result<$handle> {
+                    <$handle>::try_clone(self)
+                }
+            }
+        )*
+    };
+}
+
+io_try_clone!(File, TcpListener, TcpStream, UdpSocket);
+
+impl<T: TryClone, const N: usize> FlowerPot<T, N> {
+    /// Creates a new `FlowerPot` holding a duplicate of every item of `slice`.
+    /// returns `Err` with `CollectError::StorageFull` if `slice` holds more than `N` items,
+    /// and `CollectError::Item` with the first error duplicating an item,
+    /// in which case the items duplicated so far are dropped.
+    pub fn try_clone_from_slice(slice: &[T]) -> Result<FlowerPot<T, N>, CollectError<T::Error>> {
+        if slice.len() > N {
+            return Err(CollectError::StorageFull);
+        }
+
+        Self::collect_ok(slice.iter().map(T::try_clone))
+    }
+
+    /// Creates a new `FlowerPot` holding a duplicate of every item.
+    /// returns the first error duplicating an item,
+    /// in which case the items duplicated so far are dropped.
+    pub fn try_clone(&self) -> Result<FlowerPot<T, N>, T::Error> {
+        Self::try_clone_from_slice(self.get_init_slice()).map_err(|err| match err {
+            CollectError::Item(err) => err,
+            CollectError::StorageFull => unreachable!("a `FlowerPot` always fits its own items"),
+        })
+    }
+}
@@ -0,0 +1,214 @@
+#[cfg(feature = "alloc")]
+use crate::PotChain;
+use crate::{
+    AtomicFlowerPot, CapacityError, FlowerIndexMap, FlowerMap, FlowerMinMaxHeap, FlowerMultiMap,
+    FlowerPot, FlowerPotWithOverflow, FlowerRle, FlowerSortedMap, FlowerStrList, FlowerString,
+    SparseFlowerPot, TopK,
+};
+
+/// Bounded container that can be extended without dropping what does not fit.
+/// implemented by `FlowerPot`, `FlowerString`, `FlowerStrList`, `FlowerMap`,
+/// `FlowerSortedMap`, `FlowerIndexMap`, `FlowerMultiMap`, `FlowerMinMaxHeap`,
+/// `FlowerRle`, `SparseFlowerPot`, `AtomicFlowerPot`, `TopK`,
+/// `FlowerPotWithOverflow` and, with the `alloc` feature, `PotChain`.
+pub trait TryExtend<T> {
+    /// Adds `item` to the container.
+    /// returns `Err` with `item` if it does not fit, in which case the container is unchanged.
+    fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>>;
+
+    /// Adds every item of `iter` in order.
+    /// returns `Err` with the first item that did not fit along with the rest of the iterator,
+    /// the items before it stay in the container.
+    fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), CapacityError<(T, I::IntoIter)>> {
+        let mut iter = iter.into_iter();
+
+        while let Some(item) = iter.next() {
+            if let Err(err) = self.try_push(item) {
+                return Err(CapacityError::new((err.into_inner(), iter)));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Collects any iterator into a container implementing `TryExtend`.
+pub trait TryCollectInto: Iterator + Sized {
+    /// Adds every item to `dst` in order, see `TryExtend::try_extend`.
+    fn try_collect_into<C: TryExtend<Self::Item>>(
+        self,
+        dst: &mut C,
+    ) -> Result<(), CapacityError<(Self::Item, Self)>> {
+        dst.try_extend(self)
+    }
+}
+
+impl<I: Iterator> TryCollectInto for I {}
+
+/// Hands `item` back unless `fits`, otherwise adds it through `push`,
+/// which must not fail once `fits` holds.
+fn push_if<T>(fits: bool, item: T, push: impl FnOnce(T)) -> Result<(), CapacityError<T>> {
+    if !fits {
+        return Err(CapacityError::new(item));
+    }
+
+    push(item);
+
+    Ok(())
+}
+
+impl<T, const N: usize> TryExtend<T> for FlowerPot<T, N> {
+    fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        if self.is_full() {
+            self.record_rejected();
+
+            return Err(CapacityError::new(item));
+        }
+
+        // Can not fail, the `FlowerPot` is not full.
+        let _ = self.push(item);
+
+        Ok(())
+    }
+}
+
+impl<const N: usize> TryExtend<char> for FlowerString<N> {
+    fn try_push(&mut self, c: char) -> Result<(), CapacityError<char>> {
+        self.push(c).map_err(|_| CapacityError::new(c))
+    }
+}
+
+impl<'a, const BYTES: usize, const ITEMS: usize> TryExtend<&'a str>
+    for FlowerStrList<BYTES, ITEMS>
+{
+    fn try_push(&mut self, s: &'a str) -> Result<(), CapacityError<&'a str>> {
+        self.push_str(s).map_err(|_| CapacityError::new(s))
+    }
+}
+
+impl<K: Eq, V, const N: usize> TryExtend<(K, V)> for FlowerMap<K, V, N> {
+    /// Replaces the value if the key is already in the map.
+    fn try_push(&mut self, entry: (K, V)) -> Result<(), CapacityError<(K, V)>> {
+        let fits = !self.is_full() || self.contains_key(&entry.0);
+
+        push_if(fits, entry, |(key, value)| {
+            let _ = self.insert(key, value);
+        })
+    }
+}
+
+impl<K: Ord, V, const N: usize> TryExtend<(K, V)> for FlowerSortedMap<K, V, N> {
+    /// Replaces the value if the key is already in the map.
+    fn try_push(&mut self, entry: (K, V)) -> Result<(), CapacityError<(K, V)>> {
+        let fits = !self.is_full() || self.contains_key(&entry.0);
+
+        push_if(fits, entry, |(key, value)| {
+            let _ = self.insert(key, value);
+        })
+    }
+}
+
+impl<K: Eq, V, const N: usize> TryExtend<(K, V)> for FlowerIndexMap<K, V, N> {
+    /// Replaces the value if the key is already in the map.
+    fn try_push(&mut self, entry: (K, V)) -> Result<(), CapacityError<(K, V)>> {
+        let fits = !self.is_full() || self.contains_key(&entry.0);
+
+        push_if(fits, entry, |(key, value)| {
+            let _ = self.insert(key, value);
+        })
+    }
+}
+
+impl<K: Eq, V, const KN: usize, const VN: usize> TryExtend<(K, V)>
+    for FlowerMultiMap<K, V, KN, VN>
+{
+    /// Adds the value to the group of the key.
+    fn try_push(&mut self, entry: (K, V)) -> Result<(), CapacityError<(K, V)>> {
+        let fits = if self.contains_key(&entry.0) {
+            self.get_slice(&entry.0).len() < VN
+        } else {
+            VN > 0 && self.keys().count() < KN
+        };
+
+        push_if(fits, entry, |(key, value)| {
+            let _ = self.insert(key, value);
+        })
+    }
+}
+
+impl<T: Ord, const N: usize> TryExtend<T> for FlowerMinMaxHeap<T, N> {
+    fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        let fits = !self.is_full();
+
+        push_if(fits, item, |item| {
+            let _ = self.push(item);
+        })
+    }
+}
+
+impl<T: Eq, const N: usize> TryExtend<T> for FlowerRle<T, N> {
+    fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        let extends = self.runs().last().is_some_and(|(last, _)| *last == item);
+        let fits = extends || self.run_count() < N;
+
+        push_if(fits, item, |item| {
+            let _ = self.push(item);
+        })
+    }
+}
+
+impl<T, const N: usize, const WORDS: usize> TryExtend<T> for SparseFlowerPot<T, N, WORDS> {
+    /// Stores the item at the lowest free index.
+    fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        let fits = self.first_free().is_some();
+
+        push_if(fits, item, |item| {
+            let _ = self.insert(item);
+        })
+    }
+}
+
+impl<T, const N: usize> TryExtend<T> for AtomicFlowerPot<T, N> {
+    fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        // No other handle can push while `self` is borrowed mutably.
+        let fits = !self.is_full();
+
+        push_if(fits, item, |item| {
+            let _ = self.push(item);
+        })
+    }
+}
+
+impl<T: Ord, const K: usize> TryExtend<T> for TopK<T, K> {
+    /// Never fails, items that do not rank are dropped like with `offer`.
+    fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        self.offer(item);
+
+        Ok(())
+    }
+}
+
+impl<T, const N: usize, F> TryExtend<T> for FlowerPotWithOverflow<T, N, F>
+where
+    F: FnMut(&mut FlowerPot<T, N>, T),
+{
+    /// Never fails, items past the capacity go to the overflow callback.
+    fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        self.push(item);
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, const N: usize> TryExtend<T> for PotChain<T, N> {
+    /// Never fails, new segments are allocated as needed.
+    fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        self.push(item);
+
+        Ok(())
+    }
+}
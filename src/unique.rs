@@ -0,0 +1,44 @@
+use crate::{CapacityError, FlowerPot};
+
+impl<T: PartialEq, const N: usize> FlowerPot<T, N> {
+    /// Pushes an item if no equal item is in the `FlowerPot` yet, scanning every item.
+    /// returns `Ok(false)` if it was already there, in which case `item` is dropped,
+    /// or `Err` with `item` if it is missing but the `FlowerPot` is full.
+    pub fn push_unique(&mut self, item: T) -> Result<bool, CapacityError<T>> {
+        if self.get_init_slice().contains(&item) {
+            return Ok(false);
+        }
+
+        if self.is_full() {
+            self.record_rejected();
+
+            return Err(CapacityError::new(item));
+        }
+
+        let _ = self.push(item);
+
+        Ok(true)
+    }
+}
+
+impl<T: Ord, const N: usize> FlowerPot<T, N> {
+    /// Inserts an item into the sorted items if no equal item is there yet,
+    /// keeping them sorted and using a binary search.
+    /// returns `Ok(false)` if it was already there, in which case `item` is dropped,
+    /// or `Err` with `item` if it is missing but the `FlowerPot` is full.
+    pub fn insert_sorted_unique(&mut self, item: T) -> Result<bool, CapacityError<T>> {
+        let Err(index) = self.get_init_slice().binary_search(&item) else {
+            return Ok(false);
+        };
+
+        if self.is_full() {
+            self.record_rejected();
+
+            return Err(CapacityError::new(item));
+        }
+
+        let _ = self.insert(index, item);
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{FlowerPot, invalid_data};
+use std::io::Result;
+
+/// Incremental UTF-8 decoder for a stream of byte chunks,
+/// buffering up to `N` bytes so sequences split across chunks are kept
+/// until they are complete.
+/// `N` must be at least 4, the length of the longest sequence.
+pub struct Utf8Accumulator<const N: usize> {
+    buf: FlowerPot<u8, N>,
+    /// Length of the last returned piece, dropped before the next operation.
+    pending: usize,
+}
+
+impl<const N: usize> Utf8Accumulator<N> {
+    /// Creates a new empty `Utf8Accumulator`.
+    pub fn new() -> Utf8Accumulator<N> {
+        const { assert!(N >= 4, "`Utf8Accumulator` must hold at least 4 bytes") };
+
+        Self {
+            buf: FlowerPot::new(),
+            pending: 0,
+        }
+    }
+
+    /// Buffers as many bytes of `bytes` as fit.
+    /// returns the amount of bytes buffered,
+    /// the rest should be fed again after taking out pieces.
+    pub fn feed(&mut self, bytes: &[u8]) -> usize {
+        self.release();
+
+        self.buf.push_iter(bytes.iter().copied())
+    }
+
+    /// Returns the longest piece of complete characters at the front of the buffer.
+    /// returns `Ok(None)` if more bytes are needed to complete the next character
+    /// and `Err` with `InvalidData` if the next bytes are not valid UTF-8,
+    /// in which case they are skipped so decoding can continue.
+    pub fn next_str(&mut self) -> Result<Option<&str>> {
+        self.release();
+
+        let bytes = self.buf.get_init_slice();
+
+        let len = match std::str::from_utf8(bytes) {
+            Ok(s) => s.len(),
+            Err(err) if err.valid_up_to() > 0 => err.valid_up_to(),
+            Err(err) => match err.error_len() {
+                Some(invalid) => {
+                    self.pending = invalid;
+                    let err = invalid_data();
+
+                    return Err(err);
+                }
+                None => 0,
+            },
+        };
+
+        if len == 0 {
+            return Ok(None);
+        }
+
+        self.pending = len;
+
+        // SAFETY: `from_utf8` validated the first `len` bytes.
+        Ok(Some(unsafe {
+            std::str::from_utf8_unchecked(&bytes[..len])
+        }))
+    }
+
+    /// Obtains an immutable reference to the buffered bytes
+    /// that are not part of a returned piece.
+    pub fn buffered(&self) -> &[u8] {
+        &self.buf.get_init_slice()[self.pending..]
+    }
+
+    /// Drops every buffered byte.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+        self.pending = 0;
+    }
+
+    fn release(&mut self) {
+        self.buf.consume(self.pending);
+        self.pending = 0;
+    }
+}
+
+impl<const N: usize> Default for Utf8Accumulator<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{FlowerPot, storage_full};
+use std::io;
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Copies the initialized part of the `FlowerPot` into a new `Vec`.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.get_init_slice().to_vec()
+    }
+}
+
+impl<T, const N: usize> TryFrom<Vec<T>> for FlowerPot<T, N> {
+    type Error = io::Error;
+
+    /// Moves the items of `vec` into a new `FlowerPot`.
+    /// returns `Err` with `StorageFull` if `vec` holds more than `N` items.
+    fn try_from(vec: Vec<T>) -> io::Result<FlowerPot<T, N>> {
+        if vec.len() > N {
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        Self::try_from_iter(vec)
+    }
+}
+
+impl<T, const N: usize> From<FlowerPot<T, N>> for Vec<T> {
+    fn from(mut pot: FlowerPot<T, N>) -> Vec<T> {
+        let mut vec = Vec::with_capacity(pot.len());
+
+        while let Some(item) = pot.pop() {
+            vec.push(item);
+        }
+
+        vec.reverse();
+        vec
+    }
+}
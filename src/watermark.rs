@@ -0,0 +1,87 @@
+use crate::{FlowerPot, invalid_input};
+use std::io::Result;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Crossing reported by `Watermarks::update`.
+pub enum WatermarkEvent {
+    /// The length reached the high watermark, producers should throttle.
+    High,
+    /// The length fell to the low watermark, producers may resume.
+    Low,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// High and low watermarks with hysteresis,
+/// once the high watermark is reached the state stays throttled
+/// until the length falls to the low watermark.
+pub struct Watermarks {
+    high: usize,
+    low: usize,
+    throttled: bool,
+}
+
+impl Watermarks {
+    /// Creates new `Watermarks` throttling at `high` items and resuming at `low` items.
+    /// returns `Err` with `InvalidInput` if `low` is bigger than `high`.
+    pub fn new(high: usize, low: usize) -> Result<Watermarks> {
+        if low > high {
+            let err = invalid_input();
+
+            return Err(err);
+        }
+
+        Ok(Self {
+            high,
+            low,
+            throttled: false,
+        })
+    }
+
+    /// Creates new `Watermarks` from percentages of the capacity `N`.
+    /// returns `Err` with `InvalidInput` if `low` is bigger than `high`.
+    pub fn from_percent<const N: usize>(high: u8, low: u8) -> Result<Watermarks> {
+        Self::new(percent_of(N, high), percent_of(N, low))
+    }
+
+    /// Returns `true` if the high watermark was reached
+    /// and the low watermark was not reached since.
+    #[inline]
+    pub const fn is_throttled(&self) -> bool {
+        self.throttled
+    }
+
+    /// Feeds the current length to the watermarks.
+    /// returns the crossing that happened, if any.
+    pub fn update(&mut self, len: usize) -> Option<WatermarkEvent> {
+        if !self.throttled && len >= self.high {
+            self.throttled = true;
+
+            return Some(WatermarkEvent::High);
+        }
+
+        if self.throttled && len <= self.low {
+            self.throttled = false;
+
+            return Some(WatermarkEvent::Low);
+        }
+
+        None
+    }
+}
+
+fn percent_of(capacity: usize, percent: u8) -> usize {
+    (capacity as u128 * percent.min(100) as u128 / 100) as usize
+}
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Returns `true` if more than `percent` percent of the capacity is in use.
+    pub fn is_above(&self, percent: u8) -> bool {
+        self.pos as u128 * 100 > N as u128 * percent as u128
+    }
+
+    /// Feeds the current length to `watermarks`.
+    /// returns the crossing that happened, if any.
+    pub fn check_watermarks(&self, watermarks: &mut Watermarks) -> Option<WatermarkEvent> {
+        watermarks.update(self.pos)
+    }
+}
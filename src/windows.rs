@@ -0,0 +1,25 @@
+use crate::FlowerPot;
+
+impl<T, const N: usize> FlowerPot<T, N> {
+    /// Iterates over every overlapping window of `K` consecutive items,
+    /// yielding nothing if there are fewer than `K` items.
+    /// fails to compile if `K` is 0.
+    pub fn windows_pot<const K: usize>(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = &[T; K]> + ExactSizeIterator {
+        const { assert!(K > 0, "windows must not be empty") };
+
+        self.get_init_slice()
+            .windows(K)
+            .map(|window| window.first_chunk().unwrap())
+    }
+
+    /// Iterates over the maximal runs of adjacent items
+    /// for which `same` returns `true`, as subslices.
+    pub fn runs_by<F>(&self, same: F) -> impl DoubleEndedIterator<Item = &[T]>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.get_init_slice().chunk_by(same)
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{FlowerPot, storage_full};
+use std::fmt;
+use std::io::{self, Result};
+
+/// Buffering adapter that fills a `FlowerPot` of bytes
+/// and hands its contents to a flush callback
+/// when a write finds it full.
+/// the buffer is cleared and reused after every flush.
+/// any bytes left in the buffer are flushed on drop, ignoring errors.
+pub struct ChunkedWriter<'a, F, const N: usize>
+where
+    F: FnMut(&[u8]) -> Result<()>,
+{
+    pot: &'a mut FlowerPot<u8, N>,
+    flush: F,
+}
+
+impl<'a, F, const N: usize> ChunkedWriter<'a, F, N>
+where
+    F: FnMut(&[u8]) -> Result<()>,
+{
+    /// Creates a new `ChunkedWriter` buffering into `pot`
+    /// and flushing through `flush`.
+    pub fn new(pot: &'a mut FlowerPot<u8, N>, flush: F) -> ChunkedWriter<'a, F, N> {
+        Self { pot, flush }
+    }
+
+    /// Obtains an immutable reference to the bytes that were not flushed yet.
+    pub fn buffer(&self) -> &[u8] {
+        self.pot.get_init_slice()
+    }
+
+    fn flush_buffer(&mut self) -> Result<()> {
+        if self.pot.is_empty() {
+            return Ok(());
+        }
+
+        (self.flush)(self.pot.get_init_slice())?;
+        self.pot.clear();
+
+        Ok(())
+    }
+}
+
+impl<F, const N: usize> io::Write for ChunkedWriter<'_, F, N>
+where
+    F: FnMut(&[u8]) -> Result<()>,
+{
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        if N == 0 {
+            let err = storage_full();
+
+            return Err(err);
+        }
+
+        if self.pot.is_full() {
+            self.flush_buffer()?;
+        }
+
+        // A full buffer is only flushed before pushing,
+        // so bytes that were accepted are never reported as an error.
+        Ok(self.pot.push_iter(buf.iter().copied()))
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.flush_buffer()
+    }
+}
+
+impl<F, const N: usize> fmt::Write for ChunkedWriter<'_, F, N>
+where
+    F: FnMut(&[u8]) -> Result<()>,
+{
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        io::Write::write_all(self, s.as_bytes()).map_err(|_| fmt::Error)
+    }
+}
+
+impl<F, const N: usize> Drop for ChunkedWriter<'_, F, N>
+where
+    F: FnMut(&[u8]) -> Result<()>,
+{
+    fn drop(&mut self) {
+        let _ = self.flush_buffer();
+    }
+}
@@ -0,0 +1,39 @@
+use flowerpot::IteratorExt;
+
+#[test]
+fn chunking_into_pots() {
+    let mut chunks = (1..=7).chunks_pot::<3>();
+
+    assert!(chunks.size_hint() == (3, Some(3)));
+    assert!(chunks.next().unwrap()[..] == [1, 2, 3]);
+    assert!(chunks.next().unwrap()[..] == [4, 5, 6]);
+    assert!(
+        chunks.next().unwrap()[..] == [7],
+        "last chunk should be partial"
+    );
+    assert!(chunks.next().is_none());
+
+    assert!(std::iter::empty::<u8>().chunks_pot::<4>().next().is_none());
+    assert!("abcd".chars().chunks_pot::<2>().count() == 2);
+}
+
+#[test]
+fn looking_ahead() {
+    let mut tokens = "let x = 1;".split(' ').lookahead::<2>();
+
+    assert!(tokens.peek_nth(1) == Some(&"x"));
+    assert!(
+        tokens.peek_nth(2).is_none(),
+        "only 2 items should be peekable"
+    );
+    assert!(tokens.buffered() == 2);
+    assert!(tokens.size_hint().0 >= 2);
+
+    assert!(tokens.next_if(|token| *token == "fn").is_none());
+    assert!(tokens.next_if(|token| *token == "let") == Some("let"));
+    assert!(tokens.peek() == Some(&"x"));
+    assert!(tokens.collect::<Vec<_>>() == ["x", "=", "1;"]);
+
+    let mut empty = std::iter::empty::<u8>().lookahead::<4>();
+    assert!(empty.peek_nth(3).is_none() && empty.next().is_none());
+}
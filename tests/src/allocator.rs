@@ -0,0 +1,40 @@
+use flowerpot::FlowerPotAllocator;
+use std::alloc::{GlobalAlloc, Layout};
+
+#[test]
+fn bump_allocating() {
+    let heap = FlowerPotAllocator::<64>::new();
+    let byte = Layout::new::<u8>();
+    let word = Layout::new::<u64>();
+
+    // SAFETY: every pointer is checked for null before use
+    // and freed with the layout it was allocated with.
+    unsafe {
+        let a = heap.alloc(byte);
+        let b = heap.alloc(word);
+
+        assert!(!a.is_null() && !b.is_null());
+        assert!(b.align_offset(8) == 0, "allocation should be aligned");
+
+        b.cast::<u64>().write(u64::MAX);
+
+        let used = heap.used();
+        let c = heap.alloc(byte);
+        heap.dealloc(c, byte);
+        assert!(
+            heap.used() == used,
+            "latest allocation should be given back"
+        );
+
+        heap.dealloc(a, byte);
+        assert!(heap.used() == used, "older allocations stay until reset");
+
+        assert!(heap.alloc(Layout::new::<[u8; 64]>()).is_null());
+
+        #[cfg(not(feature = "forbid-unsafe-api"))]
+        {
+            heap.reset();
+            assert!(heap.remaining() == 64);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use flowerpot::AtomicFlowerPot;
+use std::thread;
+
+#[test]
+fn concurrent_pushes() {
+    let pot = AtomicFlowerPot::<usize, 64>::new();
+
+    thread::scope(|scope| {
+        for thread in 0..4 {
+            let pot = &pot;
+
+            scope.spawn(move || {
+                for index in 0..20 {
+                    let _ = pot.push(thread * 100 + index);
+                }
+            });
+        }
+
+        scope.spawn(|| {
+            for _ in 0..100 {
+                let len = pot.as_slice().len();
+                assert!(len <= 64, "published prefix should stay in bounds");
+            }
+        });
+    });
+
+    assert!(pot.is_full());
+    assert!(pot.push(0).is_err(), "`push` at full capacity should fail");
+
+    let items = pot.into_pot();
+
+    for thread in 0..4 {
+        let pushed = items[..]
+            .iter()
+            .filter(|&&item| item / 100 == thread)
+            .count();
+        assert!(pushed <= 20);
+    }
+
+    assert!(items.len() == 64);
+}
+
+#[test]
+fn drops_published_items() {
+    use std::rc::Rc;
+
+    let shared = Rc::new(());
+    let pot = AtomicFlowerPot::<Rc<()>, 4>::new();
+
+    pot.push(shared.clone()).unwrap();
+    pot.push(shared.clone()).unwrap();
+    assert!(pot.as_slice().len() == 2);
+
+    drop(pot);
+    assert!(
+        Rc::strong_count(&shared) == 1,
+        "items should be dropped with the pot"
+    );
+}
@@ -0,0 +1,46 @@
+use flowerpot::{BitOrder, BitReader, BitWriter, FlowerPot};
+
+#[test]
+fn msb_first_fields() {
+    let mut pot = FlowerPot::<u8, 4>::new();
+    let mut writer = BitWriter::new(&mut pot, BitOrder::MsbFirst);
+
+    writer.write_bits(0b101, 3).unwrap();
+    writer.write_bits(0b1_1111, 5).unwrap();
+    writer.write_bit(true).unwrap();
+    writer.align();
+    writer.write_bits(0xABC, 12).unwrap();
+
+    assert!(pot.get_init_slice() == [0b1011_1111, 0b1000_0000, 0xAB, 0xC0]);
+
+    let mut reader = BitReader::new(&pot, BitOrder::MsbFirst);
+    assert!(reader.read_bits(3).unwrap() == 0b101);
+    assert!(reader.read_bits(5).unwrap() == 0b1_1111);
+    assert!(reader.read_bit().unwrap());
+    reader.align();
+    assert!(reader.read_bits(12).unwrap() == 0xABC);
+    assert!(
+        reader.read_bits(5).is_err(),
+        "reading past the end should fail"
+    );
+}
+
+#[test]
+fn lsb_first_fields() {
+    let mut pot = FlowerPot::<u8, 2>::new();
+    let mut writer = BitWriter::new(&mut pot, BitOrder::LsbFirst);
+
+    writer.write_bits(0b011, 3).unwrap();
+    writer.write_bits(0b10_0110, 6).unwrap();
+    assert!(
+        writer.write_bits(0xFF, 8).is_err(),
+        "bits past the capacity should fail"
+    );
+
+    assert!(pot.get_init_slice() == [0b0011_0011, 0b0000_0001]);
+
+    let mut reader = BitReader::new(&pot, BitOrder::LsbFirst);
+    assert!(reader.read_bits(3).unwrap() == 0b011);
+    assert!(reader.read_bits(6).unwrap() == 0b10_0110);
+    assert!(reader.remaining() == 7);
+}
@@ -0,0 +1,40 @@
+use flowerpot::FlowerBloom;
+
+#[test]
+fn no_false_negatives() {
+    let mut bloom = FlowerBloom::<128>::new(3);
+
+    for id in 0..50_u32 {
+        bloom.insert(&id);
+    }
+
+    assert!((0..50_u32).all(|id| bloom.maybe_contains(&id)));
+
+    let false_positives = (1000..2000_u32)
+        .filter(|id| bloom.maybe_contains(id))
+        .count();
+    assert!(
+        false_positives < 50,
+        "too many false positives: {false_positives}"
+    );
+}
+
+#[test]
+fn merging_filters() {
+    let mut first = FlowerBloom::<32>::new(2);
+    let mut second = FlowerBloom::<32>::new(2);
+
+    first.insert("sensor-a");
+    second.insert("sensor-b");
+
+    first.union(&second).unwrap();
+    assert!(first.maybe_contains("sensor-a") && first.maybe_contains("sensor-b"));
+
+    assert!(
+        first.union(&FlowerBloom::new(4)).is_err(),
+        "different hash counts should fail"
+    );
+
+    first.clear();
+    assert!(first.count_ones() == 0);
+}
@@ -0,0 +1,166 @@
+use flowerpot::FlowerPot;
+use std::io::{self, Write};
+
+/// Writer accepting at most `limit` bytes per call.
+struct Trickle {
+    out: Vec<u8>,
+    limit: usize,
+    calls: usize,
+}
+
+impl Write for Trickle {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.calls == 0 {
+            return Err(io::ErrorKind::WouldBlock.into());
+        }
+
+        self.calls -= 1;
+
+        let len = buf.len().min(self.limit);
+        self.out.extend_from_slice(&buf[..len]);
+
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn flushing_into_writer() {
+    let mut pot = FlowerPot::<u8, 8>::try_from_iter(*b"abc").unwrap();
+    let mut out = Vec::new();
+
+    assert!(pot.flush_to(&mut out).unwrap() == 3);
+    assert!(out == b"abc");
+    assert!(pot.is_empty());
+}
+
+#[test]
+fn flushing_short_writes() {
+    let mut pot = FlowerPot::<u8, 8>::try_from_iter(*b"flowers").unwrap();
+    let mut writer = Trickle {
+        out: Vec::new(),
+        limit: 2,
+        calls: 2,
+    };
+
+    assert!(pot.flush_to(&mut writer).unwrap() == 4);
+    assert!(
+        pot.get_init_slice() == b"ers",
+        "remaining bytes should be moved to the front"
+    );
+
+    writer.calls = 8;
+    assert!(pot.flush_to(&mut writer).unwrap() == 3);
+    assert!(writer.out == b"flowers");
+}
+
+#[test]
+fn searching_bytes() {
+    let pot = FlowerPot::<u8, 64>::try_from_iter(*b"GET /index.html HTTP/1.1\r\nHost: x\r\n\r\n")
+        .unwrap();
+
+    assert!(pot.position(b'\n') == Some(25));
+    assert!(pot.position(b'!').is_none());
+    assert!(pot.find(b"\r\n\r\n") == Some(33));
+    assert!(pot.find(b"Host") == Some(26));
+    assert!(pot.find(b"") == Some(0));
+    assert!(pot.find(b"\r\n\r\n\r\n").is_none());
+
+    assert!(pot.starts_with(b"GET "));
+    assert!(pot.ends_with(b"\r\n"));
+    assert!(!pot.starts_with(b"POST"));
+}
+
+#[test]
+fn searching_every_offset() {
+    for index in 0..40 {
+        let mut pot = FlowerPot::<u8, 40>::try_from_iter(std::iter::repeat_n(0x80, 40)).unwrap();
+        pot[index] = 0x7F;
+
+        assert!(
+            pot.position(0x7F) == Some(index),
+            "word-wise search missed index {index}"
+        );
+    }
+}
+
+#[test]
+fn fixed_endian_integers() {
+    let mut pot = FlowerPot::<u8, 8>::new();
+
+    pot.put_u16_le(0x1234).unwrap();
+    pot.put_u16_be(0x1234).unwrap();
+    pot.put_u32_be(0xDEAD_BEEF).unwrap();
+
+    assert!(pot.get_init_slice() == [0x34, 0x12, 0x12, 0x34, 0xDE, 0xAD, 0xBE, 0xEF]);
+    assert!(pot.get_u16_le(0) == Some(0x1234));
+    assert!(pot.get_u32_be(4) == Some(0xDEAD_BEEF));
+    assert!(
+        pot.get_u32_le(6).is_none(),
+        "reading past the end should fail"
+    );
+
+    assert!(pot.put_u16_le(1).is_err());
+    assert!(pot.len() == 8, "a failed put should not push anything");
+}
+
+#[test]
+fn varints() {
+    let mut pot = FlowerPot::<u8, 16>::new();
+
+    assert!(pot.put_varint(300).unwrap() == 2);
+    assert!(pot.get_init_slice() == [0xAC, 0x02]);
+    assert!(pot.put_varint(u64::MAX).unwrap() == 10);
+
+    assert!(pot.get_varint(0) == Some((300, 2)));
+    assert!(pot.get_varint(2) == Some((u64::MAX, 10)));
+
+    let truncated = FlowerPot::<u8, 2>::try_from_iter([0x80, 0x80]).unwrap();
+    assert!(
+        truncated.get_varint(0).is_none(),
+        "truncated varints should fail"
+    );
+
+    let overflowing =
+        FlowerPot::<u8, 10>::try_from_iter([0xFF; 9].into_iter().chain([0x02])).unwrap();
+    assert!(
+        overflowing.get_varint(0).is_none(),
+        "overflowing varints should fail"
+    );
+}
+
+#[test]
+fn backpatch_reserved_slot() {
+    let mut pot = FlowerPot::<u8, 8>::new();
+
+    let slot = pot.reserve_slot::<2>().unwrap();
+    pot.put_slice(b"abc").unwrap();
+
+    let len = (pot.len() - slot.index() - 2) as u16;
+    pot.fill_slot(slot, len.to_be_bytes()).unwrap();
+
+    assert!(pot[..] == *b"\x00\x03abc", "length should be backpatched");
+    assert!(
+        pot.reserve_slot::<4>().is_err(),
+        "should not reserve past capacity"
+    );
+    assert!(pot.len() == 5, "failed reservation should push nothing");
+}
+
+#[test]
+fn fill_truncated_slot() {
+    let mut pot = FlowerPot::<u8, 8>::new();
+    pot.push(1).unwrap();
+
+    let slot = pot.reserve_slot::<4>().unwrap();
+    pot.truncate(3);
+
+    assert!(
+        pot.fill_slot(slot, [9; 4]).is_err(),
+        "should not fill a truncated slot"
+    );
+    assert!(pot[..] == [1, 0, 0], "failed fill should write nothing");
+}
@@ -0,0 +1,43 @@
+use flowerpot::{FlowerPot, PotChain};
+
+#[test]
+fn grows_in_segments() {
+    let mut chain = PotChain::<u32, 4>::new();
+    chain.extend(0..10);
+
+    assert!(chain.len() == 10 && chain.segment_count() == 3);
+
+    let lens: Vec<usize> = chain.segments().map(<[u32]>::len).collect();
+    assert!(lens == [4, 4, 2], "segments should fill up in order");
+    assert!(chain.iter().copied().eq(0..10));
+
+    assert!(chain.pop() == Some(9));
+    assert!(chain.pop() == Some(8));
+    assert!(
+        chain.segment_count() == 2,
+        "empty segments should be released"
+    );
+}
+
+#[test]
+fn bulk_append() {
+    let mut chain = PotChain::<u8, 4>::new();
+    chain.push(1);
+    chain.append_pot(FlowerPot::try_from_iter([2, 3, 4]).unwrap());
+    chain.append_pot(FlowerPot::new());
+
+    assert!(chain.len() == 4 && chain.segment_count() == 2);
+
+    chain.push(5);
+    assert!(
+        chain.segment_count() == 2,
+        "appended segment should be filled up"
+    );
+
+    let first = chain.pop_segment().unwrap();
+    assert!(first[..] == [1]);
+    assert!(chain.iter().copied().eq([2, 3, 4, 5]));
+
+    chain.clear();
+    assert!(chain.is_empty() && chain.pop().is_none());
+}
@@ -0,0 +1,33 @@
+use flowerpot::{Checksum, ChecksumPot, Crc32, Fletcher16};
+
+#[test]
+fn crc32_while_pushing() {
+    let mut pot = ChecksumPot::<Crc32, 16>::new(Crc32::new());
+
+    pot.put_slice(b"12345").unwrap();
+    pot.push(b'6').unwrap();
+    pot.put_slice(b"789").unwrap();
+
+    assert!(pot.digest() == 0xCBF4_3926, "invalid CRC-32 check value");
+    assert!(pot.get_init_slice() == b"123456789");
+
+    assert!(pot.put_slice(&[0; 8]).is_err());
+    assert!(
+        pot.digest() == 0xCBF4_3926,
+        "a failed push should not change the checksum"
+    );
+
+    pot.clear();
+    assert!(pot.digest() == 0);
+}
+
+#[test]
+fn fletcher16_check_value() {
+    let mut checksum = Fletcher16::new();
+    checksum.update(b"abcde");
+
+    assert!(
+        checksum.digest() == 0xC8F0,
+        "invalid Fletcher-16 check value"
+    );
+}
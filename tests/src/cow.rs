@@ -0,0 +1,30 @@
+use flowerpot::PotOrSlice;
+
+#[test]
+fn copy_on_first_write() {
+    let source = [1, 2, 3];
+    let mut items = PotOrSlice::<i32, 4>::from(&source[..]);
+
+    assert!(items.is_borrowed());
+    assert!(*items == [1, 2, 3]);
+
+    items.to_mut().unwrap().push(4).unwrap();
+    assert!(items.is_owned(), "writing should copy into inline storage");
+    assert!(*items == [1, 2, 3, 4]);
+    assert!(
+        source == [1, 2, 3],
+        "the borrowed slice should be untouched"
+    );
+}
+
+#[test]
+fn borrowed_slice_too_long() {
+    let source = [1, 2, 3, 4, 5];
+    let mut items = PotOrSlice::<i32, 4>::Borrowed(&source);
+
+    assert!(
+        items.to_mut().is_err(),
+        "copying too many items should fail"
+    );
+    assert!(items.is_borrowed());
+}
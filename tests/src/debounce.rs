@@ -0,0 +1,62 @@
+use flowerpot::Debouncer;
+
+#[test]
+fn debouncing() {
+    let mut button = Debouncer::<bool, 3>::new(false);
+
+    assert!(button.update(true).is_none());
+    assert!(button.update(false).is_none(), "bounce should be ignored");
+    assert!(button.update(true).is_none());
+    assert!(button.update(true).is_none());
+    assert!(button.update(true) == Some(true));
+    assert!(
+        button.update(true).is_none(),
+        "state should only be reported once"
+    );
+    assert!(button.state());
+
+    assert!(button.samples().copied().eq([true, true, true]));
+    button.update(false);
+    assert!(button.samples().copied().eq([true, true, false]));
+}
+
+#[test]
+fn majority_vote() {
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Level {
+        Low,
+        Mid,
+        High,
+    }
+
+    let mut level = Debouncer::<Level, 4>::new(Level::Low);
+
+    assert!(level.majority() == Some(Level::Low));
+
+    level.update(Level::High);
+    level.update(Level::Mid);
+    assert!(level.majority().is_none(), "half is not a majority");
+
+    level.update(Level::High);
+    level.update(Level::High);
+    assert!(level.majority() == Some(Level::High));
+    assert!(level.state() == Level::Low);
+}
+
+#[test]
+fn shorter_thresholds() {
+    let mut button = Debouncer::<bool, 5, 2>::new(false);
+
+    assert!(button.update(true).is_none());
+    assert!(
+        button.update(true) == Some(true),
+        "2 equal samples should be enough"
+    );
+    assert!(
+        button.majority() == Some(false),
+        "the window should stay 5 samples long"
+    );
+
+    button.update(true);
+    assert!(button.majority() == Some(true));
+}
@@ -0,0 +1,24 @@
+use flowerpot::DoubleBuffer;
+
+#[test]
+fn ping_pong() {
+    let mut buffer = DoubleBuffer::<i32, 4>::new();
+
+    buffer.back().push_iter(1..5);
+    assert!(
+        buffer.front().is_empty(),
+        "pushes should only reach the back"
+    );
+
+    buffer.swap();
+
+    let (mut front, mut back) = buffer.split();
+    assert!(front.get_init_slice() == [1, 2, 3, 4]);
+
+    back.push(5).unwrap();
+    front.clear();
+
+    buffer.swap();
+    assert!(buffer.front().get_init_slice() == [5]);
+    assert!(buffer.back().is_empty());
+}
@@ -0,0 +1,56 @@
+use flowerpot::{FlowerPot, dsp};
+
+#[test]
+fn windowed_averages() {
+    let mut averages = FlowerPot::<i16, 8>::new();
+    dsp::windowed_average(&[1, 3, 5, 7, 9], 2, &mut averages).unwrap();
+
+    assert!(averages[..] == [2, 4, 6, 8]);
+
+    let mut floats = FlowerPot::<f32, 2>::new();
+    assert!(dsp::windowed_average(&[1.0, 2.0, 3.0, 4.0], 2, &mut floats).is_err());
+    assert!(floats.is_empty(), "failed averaging should append nothing");
+
+    dsp::windowed_average(&[1.0, 2.0, 3.0], 3, &mut floats).unwrap();
+    assert!(floats[..] == [2.0]);
+
+    assert!(dsp::windowed_average(&[1.0], 0, &mut floats).is_err());
+    dsp::windowed_average(&[1.0], 2, &mut floats).unwrap();
+    assert!(floats.len() == 1, "short input should have no windows");
+}
+
+#[test]
+fn scale_and_clamp() {
+    let mut samples = FlowerPot::<f32, 4>::try_from_iter([0.5, -2.0, 4.0]).unwrap();
+    dsp::scale(&mut samples, 0.5);
+    assert!(samples[..] == [0.25, -1.0, 2.0]);
+
+    dsp::clamp(&mut samples, -0.5, 1.0);
+    assert!(samples[..] == [0.25, -0.5, 1.0]);
+
+    let half = 1 << 14;
+    let mut fixed = FlowerPot::<i16, 4>::try_from_iter([i16::MAX, i16::MIN, 100]).unwrap();
+    dsp::scale_q15(&mut fixed, half);
+    assert!(fixed[..] == [16384, -16384, 50]);
+
+    let mut loud = FlowerPot::<i16, 1>::try_from_iter([i16::MIN]).unwrap();
+    dsp::scale_q15(&mut loud, i16::MIN);
+    assert!(loud[..] == [i16::MAX], "product should saturate");
+}
+
+#[test]
+fn interleave_channels() {
+    let mut stereo = FlowerPot::<u8, 6>::new();
+    dsp::interleave(&[1, 3, 5], &[2, 4, 6], &mut stereo).unwrap();
+    assert!(stereo[..] == [1, 2, 3, 4, 5, 6]);
+
+    assert!(dsp::interleave(&[1], &[2, 3], &mut stereo).is_err());
+
+    let mut left = FlowerPot::<u8, 3>::new();
+    let mut right = FlowerPot::<u8, 3>::new();
+    dsp::deinterleave(&stereo[..], &mut left, &mut right).unwrap();
+    assert!(left[..] == [1, 3, 5]);
+    assert!(right[..] == [2, 4, 6]);
+
+    assert!(dsp::deinterleave(&[1, 2, 3], &mut left, &mut right).is_err());
+}
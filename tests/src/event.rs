@@ -0,0 +1,54 @@
+use flowerpot::FlowerEventQueue;
+
+#[derive(Debug, PartialEq)]
+enum Event {
+    Tick(u8),
+    Button,
+    Fault,
+}
+
+#[test]
+fn priorities() {
+    let mut queue = FlowerEventQueue::<Event, 2, 3>::new(usize::MAX);
+
+    queue.push(Event::Tick(0), 2).unwrap();
+    queue.push(Event::Button, 1).unwrap();
+    queue.push(Event::Tick(1), 2).unwrap();
+    queue.push(Event::Fault, 0).unwrap();
+
+    assert!(
+        queue.push(Event::Tick(2), 2).is_err(),
+        "lane should be full"
+    );
+    assert!(queue.push(Event::Fault, 3).is_err());
+    assert!(queue.len() == 4 && queue.len_at(2) == 2);
+
+    assert!(queue.pop_with_priority() == Some((0, Event::Fault)));
+    assert!(queue.pop() == Some(Event::Button));
+    assert!(queue.pop() == Some(Event::Tick(0)));
+    assert!(queue.pop() == Some(Event::Tick(1)));
+    assert!(queue.pop().is_none() && queue.is_empty());
+}
+
+#[test]
+fn fair_draining() {
+    let mut queue = FlowerEventQueue::<Event, 4, 2>::new(2);
+
+    queue.push(Event::Tick(0), 1).unwrap();
+
+    let mut order = Vec::new();
+
+    for _ in 0..3 {
+        queue.push(Event::Button, 0).unwrap();
+        order.push(queue.pop_with_priority().unwrap().0);
+    }
+
+    assert!(
+        order == [0, 0, 1],
+        "low priority should be popped after running out of patience"
+    );
+    assert!(queue.len() == 1);
+
+    queue.clear();
+    assert!(queue.is_empty());
+}
@@ -0,0 +1,185 @@
+use flowerpot::frame::{FrameBuilder, FrameParser, Framing};
+use flowerpot::{FlowerPot, cobs, slip};
+
+#[test]
+fn cobs_round_trip() {
+    let cases: [(&[u8], &[u8]); 5] = [
+        (&[0x00], &[0x01, 0x01]),
+        (&[0x00, 0x00], &[0x01, 0x01, 0x01]),
+        (&[0x11, 0x22, 0x00, 0x33], &[0x03, 0x11, 0x22, 0x02, 0x33]),
+        (&[0x11, 0x00, 0x00, 0x00], &[0x02, 0x11, 0x01, 0x01, 0x01]),
+        (&[], &[0x01]),
+    ];
+
+    for (raw, encoded) in cases {
+        let mut pot = FlowerPot::<u8, 16>::new();
+        cobs::encode(raw, &mut pot).unwrap();
+        assert!(
+            pot.get_init_slice() == encoded,
+            "invalid encoding of {raw:?}"
+        );
+
+        let mut out = FlowerPot::<u8, 16>::new();
+        cobs::decode(encoded, &mut out).unwrap();
+        assert!(
+            out.get_init_slice() == raw,
+            "invalid decoding of {encoded:?}"
+        );
+
+        cobs::decode_in_place(&mut pot).unwrap();
+        assert!(
+            pot.get_init_slice() == raw,
+            "invalid in place decoding of {encoded:?}"
+        );
+    }
+}
+
+#[test]
+fn cobs_long_runs() {
+    let raw: Vec<u8> = (1..=255).collect();
+    let mut pot = FlowerPot::<u8, 300>::new();
+
+    cobs::encode(&raw, &mut pot).unwrap();
+    assert!(pot.len() == 257);
+    assert!(pot[0] == 0xFF && pot[255] == 0x02);
+    assert!(
+        pot.position(0).is_none(),
+        "encoded frames must not contain zeros"
+    );
+
+    pot.push(0).unwrap();
+    cobs::decode_in_place(&mut pot).unwrap();
+    assert!(pot.get_init_slice() == raw.as_slice());
+}
+
+#[test]
+fn cobs_errors() {
+    let mut pot = FlowerPot::<u8, 4>::new();
+
+    assert!(
+        cobs::decode(&[0x05, 0x11], &mut pot).is_err(),
+        "truncated block"
+    );
+    assert!(
+        cobs::decode(&[0x02, 0x00, 0x01], &mut pot).is_err(),
+        "zero inside of frame"
+    );
+    assert!(
+        cobs::encode(&[1, 2, 3, 4], &mut pot).is_err(),
+        "output too small"
+    );
+    assert!(pot.is_empty(), "failed calls should not append anything");
+}
+
+#[test]
+fn slip_round_trip() {
+    let raw = [0x01, slip::END, 0x02, slip::ESC];
+    let mut pot = FlowerPot::<u8, 16>::new();
+
+    slip::encode(&raw, &mut pot).unwrap();
+    assert!(
+        pot.get_init_slice()
+            == [
+                0x01,
+                slip::ESC,
+                slip::ESC_END,
+                0x02,
+                slip::ESC,
+                slip::ESC_ESC,
+                slip::END
+            ]
+    );
+
+    let mut out = FlowerPot::<u8, 16>::new();
+    assert!(slip::decode(&pot[..], &mut out).unwrap() == 4);
+    assert!(out.get_init_slice() == raw);
+
+    assert!(slip::decode(&[slip::ESC, 0x01], &mut out).is_err());
+    assert!(out.len() == 4, "failed calls should not append anything");
+}
+
+#[test]
+fn length_prefixed_frames() {
+    let mut stream = FlowerPot::<u8, 32>::new();
+
+    let mut builder = FrameBuilder::new(&mut stream, Framing::LengthPrefixed).unwrap();
+    builder.put_slice(b"abc").unwrap();
+    assert!(
+        builder.finish().unwrap() == 5,
+        "frame should include the prefix"
+    );
+
+    let mut builder = FrameBuilder::new(&mut stream, Framing::LengthPrefixed).unwrap();
+    builder.put_slice(b"dropped").unwrap();
+    drop(builder);
+
+    let builder = FrameBuilder::new(&mut stream, Framing::LengthPrefixed).unwrap();
+    builder.finish().unwrap();
+
+    assert!(
+        stream[..] == *b"\x00\x03abc\x00\x00",
+        "dropped frame should be rolled back"
+    );
+
+    let mut parser = FrameParser::<8>::new(Framing::LengthPrefixed);
+
+    assert!(parser.feed(&stream[..3]) == 3);
+    assert!(
+        parser.next_frame().unwrap().is_none(),
+        "frame should be incomplete"
+    );
+
+    assert!(parser.feed(&stream[3..]) == 4);
+    assert!(parser.next_frame().unwrap() == Some(&b"abc"[..]));
+    assert!(parser.next_frame().unwrap() == Some(&b""[..]));
+    assert!(parser.next_frame().unwrap().is_none());
+    assert!(parser.buffered().is_empty());
+}
+
+#[test]
+fn delimited_frames() {
+    let mut parser = FrameParser::<8>::new(Framing::Delimited(b'\n'));
+
+    assert!(parser.feed(b"ab\ncd") == 5);
+    assert!(parser.next_frame().unwrap() == Some(&b"ab"[..]));
+    assert!(
+        parser.next_frame().unwrap().is_none(),
+        "frame should be incomplete"
+    );
+
+    assert!(parser.feed(b"e\n") == 2);
+    assert!(parser.next_frame().unwrap() == Some(&b"cde"[..]));
+
+    assert!(
+        parser.feed(b"too long!") == 8,
+        "should buffer only what fits"
+    );
+    assert!(parser.next_frame().is_err(), "frame can never fit");
+
+    parser.clear();
+    assert!(parser.buffered().is_empty());
+}
+
+#[test]
+fn oversized_length_prefix() {
+    let mut parser = FrameParser::<8>::new(Framing::LengthPrefixed);
+    parser.feed(&[0x00, 0x07]);
+
+    assert!(parser.next_frame().is_err(), "frame can never fit");
+}
+
+#[test]
+fn delimiters_in_payloads() {
+    let mut stream = FlowerPot::<u8, 16>::new();
+
+    let mut builder = FrameBuilder::new(&mut stream, Framing::Delimited(b'\n')).unwrap();
+    builder.put_slice(b"ok").unwrap();
+    assert!(builder.finish().unwrap() == 3);
+
+    let mut builder = FrameBuilder::new(&mut stream, Framing::Delimited(b'\n')).unwrap();
+    builder.put_slice(b"two\nlines").unwrap();
+    let err = builder.finish().unwrap_err();
+
+    assert!(err.kind() == std::io::ErrorKind::InvalidData);
+    assert!(stream[..] == *b"ok\n", "a rejected frame should be dropped");
+}
@@ -0,0 +1,46 @@
+use flowerpot::FlowerGraph;
+
+#[test]
+fn adding_edges() {
+    let mut graph = FlowerGraph::<4, 2>::new();
+
+    assert!(graph.add_edge(0, 1).unwrap());
+    assert!(
+        !graph.add_edge(0, 1).unwrap(),
+        "duplicate edges should not be added"
+    );
+    assert!(graph.add_edge(0, 2).unwrap());
+    assert!(graph.add_edge(0, 3).is_err(), "degree should be bounded");
+    assert!(graph.add_edge(4, 0).is_err() && graph.add_edge(0, 4).is_err());
+
+    assert!(graph.neighbors(0) == [1, 2] && graph.degree(0) == 2);
+    assert!(graph.has_edge(0, 2) && !graph.has_edge(2, 0));
+
+    assert!(graph.add_undirected_edge(3, 0).is_err());
+    assert!(
+        !graph.has_edge(3, 0),
+        "failed undirected edges should add nothing"
+    );
+
+    assert!(graph.remove_edge(0, 1) && !graph.remove_edge(0, 1));
+    assert!(graph.edge_count() == 1);
+
+    graph.clear();
+    assert!(graph.edge_count() == 0);
+}
+
+#[test]
+fn breadth_first_search() {
+    let mut graph = FlowerGraph::<6, 3>::new();
+
+    for (a, b) in [(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)] {
+        graph.add_undirected_edge(a, b).unwrap();
+    }
+
+    assert!(graph.bfs(0).collect::<Vec<_>>() == [0, 1, 2, 3, 4]);
+    assert!(graph.bfs(5).collect::<Vec<_>>() == [5]);
+    assert!(graph.bfs(6).next().is_none());
+
+    let distances = graph.distances(0);
+    assert!(distances == [Some(0), Some(1), Some(1), Some(2), Some(3), None]);
+}
@@ -0,0 +1,44 @@
+use flowerpot::FlowerIndexMap;
+
+#[test]
+fn insertion_order() {
+    let mut map = FlowerIndexMap::<&str, u32, 4>::new();
+
+    map.insert("baud", 9600).unwrap();
+    map.insert("parity", 0).unwrap();
+    map.insert("stop", 1).unwrap();
+    assert!(map.insert("baud", 115_200).unwrap() == Some(9600));
+
+    assert!(map.keys().copied().eq(["baud", "parity", "stop"]));
+    assert!(map.get_index(0) == Some((&"baud", &115_200)));
+    assert!(map.get_index_of("stop") == Some(2));
+    assert!(map.get_index(3).is_none());
+}
+
+#[test]
+fn removal_semantics() {
+    let mut map = FlowerIndexMap::<char, u32, 4>::new();
+
+    for (index, key) in "abcd".chars().enumerate() {
+        map.insert(key, index as u32).unwrap();
+    }
+
+    assert!(
+        map.insert('e', 4).is_err(),
+        "new keys past the capacity should fail"
+    );
+
+    assert!(map.shift_remove(&'b') == Some(1));
+    assert!(
+        map.keys().copied().eq(['a', 'c', 'd']),
+        "order should be kept"
+    );
+
+    assert!(map.swap_remove(&'a') == Some(0));
+    assert!(
+        map.keys().copied().eq(['d', 'c']),
+        "last entry should move in"
+    );
+
+    assert!(map.swap_remove(&'z').is_none());
+}
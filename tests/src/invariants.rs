@@ -0,0 +1,90 @@
+use flowerpot::FlowerPot;
+use std::rc::Rc;
+
+/// Small xorshift generator, so the sequences are the same on every run.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self, bound: usize) -> usize {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+
+        (self.0 % bound as u64) as usize
+    }
+}
+
+#[test]
+fn random_operations_match_vec() {
+    let tracker = Rc::new(());
+    let mut rng = Rng(0x5EED);
+    let mut id = 0;
+
+    for _ in 0..200 {
+        let mut pot = FlowerPot::<(u32, Rc<()>), 5>::new();
+        let mut model: Vec<(u32, Rc<()>)> = Vec::new();
+
+        for _ in 0..64 {
+            let len = model.len();
+            let full = len == 5;
+            id += 1;
+            let item = || (id, tracker.clone());
+
+            match rng.next(7) {
+                0 => {
+                    assert!(pot.push(item()).is_ok() != full);
+
+                    if !full {
+                        model.push(item());
+                    }
+                }
+                1 => assert!(pot.pop().map(|item| item.0) == model.pop().map(|item| item.0)),
+                2 => {
+                    let index = rng.next(len + 2);
+                    let pushed = pot.insert(index, item()).is_ok();
+
+                    assert!(pushed == (!full && index <= len));
+
+                    if pushed {
+                        model.insert(index, item());
+                    }
+                }
+                3 => {
+                    let index = rng.next(len + 1);
+                    let removed = pot.remove(index).map(|item| item.0);
+
+                    assert!(removed == (index < len).then(|| model.remove(index).0));
+                }
+                4 => {
+                    let index = rng.next(len + 1);
+                    let removed = pot.swap_remove(index).map(|item| item.0);
+
+                    assert!(removed == (index < len).then(|| model.swap_remove(index).0));
+                }
+                5 => {
+                    let len = rng.next(6);
+                    pot.truncate(len);
+                    model.truncate(len);
+                }
+                _ => {
+                    let n = rng.next(3);
+                    pot.consume(n);
+                    model.drain(..n.min(model.len()));
+                }
+            }
+
+            assert!(
+                pot.get_init_slice()
+                    .iter()
+                    .map(|item| item.0)
+                    .eq(model.iter().map(|item| item.0))
+            );
+            assert!(
+                Rc::strong_count(&tracker) == 1 + pot.len() + model.len(),
+                "every item should be dropped exactly once"
+            );
+        }
+    }
+
+    assert!(Rc::strong_count(&tracker) == 1);
+}
@@ -0,0 +1,63 @@
+use flowerpot::FlowerPot;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+#[test]
+fn backpatch_through_seek() {
+    let mut pot = FlowerPot::<u8, 16>::new();
+    let mut cursor = pot.io_cursor();
+
+    cursor.write_all(&[0, 0]).unwrap();
+    cursor.write_all(b"hello").unwrap();
+
+    let end = cursor.stream_position().unwrap();
+    cursor.seek(SeekFrom::Start(0)).unwrap();
+    cursor.write_all(&5u16.to_le_bytes()).unwrap();
+    cursor.seek(SeekFrom::Start(end)).unwrap();
+    cursor.write_all(b"!").unwrap();
+
+    assert!(
+        pot[..] == *b"\x05\x00hello!",
+        "length should be backpatched"
+    );
+}
+
+#[test]
+fn read_and_seek_relative() {
+    let mut pot = FlowerPot::<u8, 8>::new();
+    pot.put_slice(b"abcdef").unwrap();
+
+    let mut cursor = pot.io_cursor();
+    let mut buf = [0; 2];
+
+    cursor.seek(SeekFrom::End(-2)).unwrap();
+    cursor.read_exact(&mut buf).unwrap();
+    assert!(buf == *b"ef", "should read from the end");
+
+    cursor.seek(SeekFrom::Current(-4)).unwrap();
+    cursor.read_exact(&mut buf).unwrap();
+    assert!(buf == *b"cd", "should read relative to the position");
+
+    assert!(
+        cursor.seek(SeekFrom::Current(-10)).is_err(),
+        "should not seek before the start"
+    );
+    assert!(
+        cursor.read(&mut buf).unwrap() == 2,
+        "A failed seek should keep the position"
+    );
+}
+
+#[test]
+fn write_past_end() {
+    let mut pot = FlowerPot::<u8, 6>::new();
+    let mut cursor = pot.io_cursor();
+
+    cursor.seek(SeekFrom::Start(2)).unwrap();
+    assert!(
+        cursor.write(b"abcdef").unwrap() == 4,
+        "should write only what fits"
+    );
+    assert!(cursor.write(b"g").is_err(), "should fail when full");
+
+    assert!(pot[..] == *b"\0\0abcd", "gap should be zero filled");
+}
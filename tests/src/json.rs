@@ -0,0 +1,41 @@
+use flowerpot::{FlowerMap, FlowerPot, FlowerString, ToJson};
+
+#[test]
+fn rendering_values() {
+    let mut out = FlowerPot::<u8, 64>::new();
+
+    let readings = FlowerPot::<f32, 4>::try_from_iter([1.5, -2.0, f32::NAN]).unwrap();
+    readings.to_json_into(&mut out).unwrap();
+    assert!(out[..] == *b"[1.5,-2,null]");
+
+    out.clear();
+    let name: FlowerString<16> = "a\"b\\\n\u{1}".parse().unwrap();
+    name.to_json_into(&mut out).unwrap();
+    assert!(out[..] == *b"\"a\\\"b\\\\\\n\\u0001\"");
+
+    out.clear();
+    let mut map = FlowerMap::<&str, Option<u8>, 4>::new();
+    map.insert("t", Some(21)).unwrap();
+    map.insert("h", None).unwrap();
+    map.to_json_into(&mut out).unwrap();
+    assert!(out[..] == *b"{\"t\":21,\"h\":null}");
+}
+
+#[test]
+fn rendering_into_full_pots() {
+    let mut out = FlowerPot::<u8, 8>::new();
+    let _ = out.put_slice(b"x=");
+
+    let nested = FlowerPot::<FlowerPot<u8, 2>, 2>::try_from_iter([
+        FlowerPot::try_from_iter([1, 2]).unwrap(),
+        FlowerPot::try_from_iter([3]).unwrap(),
+    ])
+    .unwrap();
+
+    assert!(nested.to_json_into(&mut out).is_err());
+    assert!(out[..] == *b"x=", "failed renders should append nothing");
+
+    let mut out = FlowerPot::<u8, 11>::new();
+    nested.to_json_into(&mut out).unwrap();
+    assert!(out[..] == *b"[[1,2],[3]]");
+}
@@ -0,0 +1,56 @@
+use flowerpot::{LineBuffer, Overlong};
+
+#[test]
+fn splitting_lines() {
+    let mut lines = LineBuffer::<16>::new(Overlong::Discard);
+
+    assert!(lines.feed(b"AT\r\nOK") == 6);
+    assert!(lines.next_line() == Some(&b"AT"[..]));
+    assert!(lines.next_line().is_none(), "partial line should wait");
+
+    lines.feed(b"\n\nbye\n");
+    assert!(lines.next_line() == Some(&b"OK"[..]));
+    assert!(lines.next_line() == Some(&b""[..]));
+    assert!(lines.next_line() == Some(&b"bye"[..]));
+    assert!(lines.next_line().is_none());
+    assert!(lines.buffered().is_empty());
+}
+
+#[test]
+fn overlong_lines() {
+    let input = b"abcdefg\nhi\n";
+
+    let mut split = LineBuffer::<4>::new(Overlong::Split);
+    let mut out = Vec::new();
+    let mut rest = &input[..];
+
+    while !rest.is_empty() {
+        let used = split.feed(rest);
+        rest = &rest[used..];
+
+        while let Some(line) = split.next_line() {
+            out.push(line.to_vec());
+        }
+    }
+
+    assert!(out == [b"abcd".to_vec(), b"efg".to_vec(), b"hi".to_vec()]);
+
+    let mut truncate = LineBuffer::<4>::new(Overlong::Truncate);
+
+    assert!(truncate.feed(input) == 4);
+    assert!(truncate.next_line().is_none(), "overlong line is detected");
+    assert!(
+        truncate.feed(&input[4..]) == 4,
+        "line break should be dropped too"
+    );
+    assert!(truncate.next_line() == Some(&b"abcd"[..]));
+    assert!(truncate.feed(&input[8..]) == 3);
+    assert!(truncate.next_line() == Some(&b"hi"[..]));
+
+    let mut discard = LineBuffer::<4>::new(Overlong::Discard);
+
+    assert!(discard.feed(input) == 4);
+    assert!(discard.next_line().is_none());
+    assert!(discard.feed(&input[4..]) == 7);
+    assert!(discard.next_line() == Some(&b"hi"[..]));
+}
@@ -0,0 +1,92 @@
+use flowerpot::{FlowerLog, dump};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+fn contents<const BYTES: usize>(log: &FlowerLog<BYTES>) -> Vec<Vec<u8>> {
+    log.iter().map(|record| record.bytes().collect()).collect()
+}
+
+#[test]
+fn evicts_oldest_records() {
+    let mut log = FlowerLog::<32>::new();
+
+    log.push(b"first").unwrap();
+    log.push(b"second").unwrap();
+    assert!(log.len() == 2 && log.bytes_used() == 31);
+
+    log.push(b"third").unwrap();
+    assert!(
+        contents(&log) == [b"second".to_vec(), b"third".to_vec()],
+        "oldest record should be evicted"
+    );
+
+    log.push(b"wraps around").unwrap();
+    assert!(contents(&log) == [b"wraps around".to_vec()]);
+
+    let record = log.iter().next().unwrap();
+    let (first, second) = record.as_slices();
+    assert!(
+        !second.is_empty() && first.len() + second.len() == 12,
+        "record should wrap around"
+    );
+
+    assert!(log.push(&[0; 23]).is_err(), "record can never fit");
+    assert!(log.len() == 1, "failed push should evict nothing");
+}
+
+#[test]
+fn stamps_through_clock() {
+    static NOW: AtomicU64 = AtomicU64::new(100);
+
+    fn clock() -> u64 {
+        NOW.fetch_add(1, Ordering::Relaxed)
+    }
+
+    let mut log = FlowerLog::<64>::with_clock(clock);
+    log.push(b"a").unwrap();
+    log.push(b"").unwrap();
+    log.push_with_timestamp(7, b"b").unwrap();
+
+    let stamps: Vec<u64> = log.iter().map(|record| record.timestamp()).collect();
+    assert!(stamps == [100, 101, 7]);
+
+    assert!(log.evict());
+    log.clear();
+    assert!(log.is_empty() && !log.evict());
+}
+
+#[test]
+fn parse_from_dump() {
+    let mut log = FlowerLog::<32>::new();
+    log.push_with_timestamp(1, b"lost").unwrap();
+    log.push_with_timestamp(2, b"kept").unwrap();
+    log.push_with_timestamp(3, b"wrapped").unwrap();
+
+    let mut ram = vec![0xAA; 16];
+    ram.extend_from_slice(log.dump_bytes());
+    ram.extend_from_slice(&[0x55; 16]);
+
+    let dumped = dump::parse(&ram[16..]).unwrap();
+    let records: Vec<(u64, Vec<u8>)> = dumped
+        .iter()
+        .map(|record| (record.timestamp(), record.bytes().collect()))
+        .collect();
+
+    assert!(dumped.capacity() == 32);
+    assert!(records == [(2, b"kept".to_vec()), (3, b"wrapped".to_vec())]);
+
+    assert!(
+        dump::parse(&ram).is_err(),
+        "should not parse without the magic number"
+    );
+    assert!(
+        dump::parse(&ram[16..40]).is_err(),
+        "should not parse a truncated log"
+    );
+
+    let mut corrupted = ram[16..].to_vec();
+    corrupted[24] ^= 0xFF;
+    assert!(
+        dump::parse(&corrupted).is_err(),
+        "should notice a corrupted header"
+    );
+}
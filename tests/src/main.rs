@@ -1,17 +1,110 @@
+#[cfg(test)]
+mod adapters;
+#[cfg(test)]
+mod atomic;
+#[cfg(test)]
+mod bits;
+#[cfg(test)]
+mod bloom;
+#[cfg(test)]
+mod bytes;
+#[cfg(test)]
+mod checksum;
+#[cfg(test)]
+mod cow;
+#[cfg(test)]
+mod debounce;
+#[cfg(test)]
+mod double;
+#[cfg(test)]
+mod dsp;
+#[cfg(test)]
+mod event;
+#[cfg(test)]
+mod framing;
+#[cfg(test)]
+mod graph;
+#[cfg(test)]
+mod index_map;
+#[cfg(test)]
+mod invariants;
+#[cfg(test)]
+mod io_cursor;
+#[cfg(test)]
+mod lines;
+#[cfg(test)]
+mod log;
+#[cfg(test)]
+mod map;
+#[cfg(test)]
+mod min_max_heap;
+#[cfg(test)]
+mod multi_map;
+#[cfg(test)]
+mod overflow;
+#[cfg(test)]
+mod pipe;
+#[cfg(test)]
+mod pool;
+#[cfg(test)]
+mod prelude;
+#[cfg(test)]
+mod rate;
+#[cfg(test)]
+mod record;
+#[cfg(test)]
+mod rle;
+#[cfg(test)]
+mod seqlock;
+#[cfg(test)]
+mod sharded;
+#[cfg(test)]
+mod simd;
+#[cfg(test)]
+mod sorted_map;
+#[cfg(test)]
+mod sparse;
+#[cfg(test)]
+mod steal;
+#[cfg(test)]
+mod str_list;
+#[cfg(test)]
+mod string;
+#[cfg(test)]
+mod top_k;
+#[cfg(test)]
+mod try_extend;
+#[cfg(test)]
+mod watermark;
+#[cfg(test)]
+mod writer;
+
+#[cfg(all(test, feature = "allocator"))]
+mod allocator;
+
+#[cfg(all(test, feature = "alloc"))]
+mod chain;
+
+#[cfg(all(test, feature = "json"))]
+mod json;
+
+fn main() {}
+
 #[cfg(test)]
 mod tests {
     use flowerpot::FlowerPot;
 
     #[test]
+    #[allow(clippy::op_ref)]
     fn pushing() {
         let mut pot = FlowerPot::<i32, 4>::new();
 
         assert!(
-            !pot.full(),
+            !pot.is_full(),
             "unexpected: `FlowerPot` was not meant to be full at this stage"
         );
         assert!(
-            pot.empty(),
+            pot.is_empty(),
             "unexpected: `FlowerPot` was meant to be empty at this stage"
         );
 
@@ -26,6 +119,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::int_plus_one, clippy::op_ref)]
     fn trying_till_full() {
         const SIZE: usize = 4;
         let mut pot = FlowerPot::<i32, SIZE>::new();
@@ -60,6 +154,1051 @@ mod tests {
 
         assert!(pot.pop().is_none());
 
-        assert!(pot.empty());
+        assert!(pot.is_empty());
+    }
+
+    #[test]
+    fn slot_manipulation() {
+        let mut pot = FlowerPot::<i32, 4>::new();
+
+        [1, 2, 3, 4]
+            .into_iter()
+            .for_each(|number| pot.push(number).unwrap());
+
+        assert!(pot.swap(0, 3));
+        assert!(!pot.swap(0, 4), "`swap` out of bounds should fail");
+        assert!(pot.get_init_slice() == [4, 2, 3, 1]);
+
+        assert!(pot.replace(1, 5) == Some(2));
+        assert!(pot.replace(4, 5).is_none());
+        assert!(pot.take(2) == Some(3));
+        assert!(pot.get_init_slice() == [4, 5, 0, 1]);
+
+        assert!(pot.swap_remove(0) == Some(4));
+        assert!(pot.get_init_slice() == [1, 5, 0]);
+        assert!(pot.swap_remove(3).is_none());
+        assert!(pot.get(3).is_none(), "`get` past the length should fail");
+    }
+
+    #[test]
+    fn capacity_introspection() {
+        let mut pot = FlowerPot::<i32, 4>::new();
+
+        assert!(pot.capacity() == 4);
+        assert!(pot.remaining_capacity() == 4);
+
+        pot.push(1).unwrap();
+        pot.push(2).unwrap();
+
+        assert!(
+            pot.len() == 2,
+            "`len` should match the amount of pushed items"
+        );
+        assert!(pot.remaining_capacity() == 2);
+        assert!(!pot.is_full() && !pot.is_empty());
+    }
+
+    #[test]
+    fn pushing_from_iterators() {
+        let mut pot = FlowerPot::<i32, 4>::new();
+
+        assert!(pot.push_iter([1, 2]) == 2);
+        assert!(
+            pot.push_iter(3..10) == 2,
+            "only the free space should be filled"
+        );
+        assert!(pot.get_init_slice() == [1, 2, 3, 4]);
+
+        pot.pop();
+        pot.pop();
+
+        let mut rest = pot.push_iter_rest(5..9);
+        assert!(rest.next() == Some(7), "unconsumed items should be kept");
+        assert!(pot.get_init_slice() == [1, 2, 5, 6]);
+    }
+
+    #[test]
+    fn constructing_from_iterators() {
+        let pot = FlowerPot::<i32, 4>::try_from_iter(1..4).unwrap();
+        assert!(pot.get_init_slice() == [1, 2, 3]);
+
+        assert!(
+            FlowerPot::<i32, 4>::try_from_iter(1..6).is_err(),
+            "too many items should fail"
+        );
+
+        let pot = FlowerPot::<i32, 4>::from_iter_exact(1..5).unwrap();
+        assert!(pot.get_init_slice() == [1, 2, 3, 4]);
+
+        let err = FlowerPot::<i32, 4>::from_iter_exact(1..4).unwrap_err();
+        assert!(err.kind() == std::io::ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn transactions() {
+        let mut pot = FlowerPot::<i32, 4>::new();
+        pot.push(1).unwrap();
+
+        let mut tx = pot.transaction();
+        tx.push(2).unwrap();
+        tx.push(3).unwrap();
+        assert!(tx.staged() == [2, 3]);
+        tx.commit();
+
+        let mut tx = pot.transaction();
+        tx.push(4).unwrap();
+        assert!(tx.push(5).is_err(), "staging past the capacity should fail");
+        tx.rollback();
+
+        assert!(
+            pot.get_init_slice() == [1, 2, 3],
+            "rolled back items should be dropped"
+        );
+
+        pot.truncate(1);
+        assert!(pot.get_init_slice() == [1]);
+    }
+
+    #[test]
+    fn checkpoints() {
+        let mut pot = FlowerPot::<i32, 8>::new();
+        pot.push(1).unwrap();
+
+        let outer = pot.checkpoint();
+        pot.push(2).unwrap();
+
+        let inner = pot.checkpoint();
+        pot.push_iter([3, 4, 5]);
+
+        pot.restore(inner);
+        assert!(
+            pot.get_init_slice() == [1, 2],
+            "backtracking should drop items"
+        );
+
+        pot.restore(outer);
+        assert!(pot.get_init_slice() == [1]);
+        assert!(outer.position() == 1);
+    }
+
+    #[test]
+    fn inserting_and_removing() {
+        let mut pot = FlowerPot::<i32, 4>::new();
+
+        pot.insert(0, 2).unwrap();
+        pot.insert(0, 1).unwrap();
+        pot.insert(2, 4).unwrap();
+        pot.insert(2, 3).unwrap();
+        assert!(pot.get_init_slice() == [1, 2, 3, 4]);
+        assert!(
+            pot.insert(1, 5).is_err(),
+            "`insert` at full capacity should fail"
+        );
+
+        assert!(pot.remove(1) == Some(2));
+        assert!(pot.remove(3).is_none());
+        assert!(pot.get_init_slice() == [1, 3, 4]);
+    }
+
+    #[test]
+    fn cursor_editing() {
+        let mut pot = FlowerPot::<char, 8>::new();
+        pot.push_iter("helo".chars());
+
+        let mut cursor = pot.cursor_mut();
+        assert!(cursor.seek(3));
+        assert!(cursor.current() == Some(&'o'));
+
+        cursor.insert('l').unwrap();
+        assert!(
+            cursor.current() == Some(&'o'),
+            "cursor should stay on its item"
+        );
+        assert!(cursor.peek_prev() == Some(&'l'));
+
+        assert!(cursor.move_next());
+        assert!(
+            !cursor.move_next(),
+            "cursor should stop past the final item"
+        );
+        assert!(cursor.current().is_none());
+
+        cursor.seek(0);
+        assert!(cursor.remove_current() == Some('h'));
+        cursor.insert('j').unwrap();
+
+        assert!(pot.get_init_slice() == ['j', 'e', 'l', 'l', 'o']);
+    }
+
+    #[test]
+    fn indexing() {
+        let mut pot = FlowerPot::<i32, 4>::try_from_iter(1..4).unwrap();
+
+        pot[1] *= 10;
+        assert!(pot[1] == 20);
+        assert!(pot[1..] == [20, 3]);
+
+        pot[..2].copy_from_slice(&[7, 8]);
+        assert!(pot.get_init_slice() == [7, 8, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn indexing_past_length() {
+        let pot = FlowerPot::<i32, 4>::try_from_iter(1..4).unwrap();
+
+        let _ = pot[3];
+    }
+
+    #[test]
+    fn reference_conversions() {
+        fn total(items: impl AsRef<[i32]>) -> i32 {
+            items.as_ref().iter().sum()
+        }
+
+        let mut pot = FlowerPot::<i32, 4>::try_from_iter(1..4).unwrap();
+        assert!(total(&pot) == 6);
+
+        pot.as_mut()[0] = 4;
+        let borrowed: &[i32] = std::borrow::Borrow::borrow(&pot);
+        assert!(borrowed == [4, 2, 3]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn vec_conversions() {
+        let pot = FlowerPot::<i32, 4>::try_from(vec![1, 2, 3]).unwrap();
+        assert!(pot.to_vec() == [1, 2, 3]);
+
+        let vec: Vec<i32> = pot.into();
+        assert!(vec == [1, 2, 3]);
+
+        assert!(
+            FlowerPot::<i32, 2>::try_from(vec).is_err(),
+            "a longer `Vec` should not fit"
+        );
+    }
+
+    #[test]
+    fn consuming_from_front() {
+        let counter = std::rc::Rc::new(());
+        let mut pot = FlowerPot::<std::rc::Rc<()>, 4>::new();
+        pot.push_iter(std::iter::repeat_with(|| counter.clone()).take(4));
+
+        pot.consume(3);
+        assert!(pot.len() == 1);
+        assert!(
+            std::rc::Rc::strong_count(&counter) == 2,
+            "consumed items should be dropped"
+        );
+
+        let mut pot = FlowerPot::<u8, 8>::try_from_iter(*b"hdr:body").unwrap();
+        pot.consume(4);
+        assert!(pot.get_init_slice() == b"body");
+
+        pot.consume(10);
+        assert!(pot.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn usage_metrics() {
+        let mut pot = FlowerPot::<u8, 4>::new();
+
+        pot.push_iter([1, 2, 3]);
+        pot.pop();
+        pot.pop();
+        assert!(pot.high_water_mark() == 3);
+
+        pot.push_iter([4, 5, 6]);
+        assert!(pot.push(7).is_err());
+        assert!(pot.insert(0, 7).is_err());
+        assert!(pot.put_slice(&[7, 8]).is_err());
+        assert!(pot.rejected_pushes() == 3);
+        assert!(pot.high_water_mark() == 4);
+
+        pot.truncate(1);
+        pot.reset_high_water_mark();
+        pot.reset_rejected_pushes();
+        assert!(pot.high_water_mark() == 1 && pot.rejected_pushes() == 0);
+    }
+
+    #[test]
+    fn capacity_assertions() {
+        fn framed<const N: usize>(pot: &mut FlowerPot<u8, N>) {
+            FlowerPot::<u8, N>::assert_capacity_at_least::<4>();
+
+            pot.put_slice(&[0xAA; 4]).unwrap();
+        }
+
+        let mut pot = FlowerPot::<u8, 8>::new();
+        framed(&mut pot);
+        FlowerPot::<u8, 8>::assert_capacity_at_most::<8>();
+
+        assert!(pot.capacity() == FlowerPot::<u8, 8>::CAPACITY);
+        let () = flowerpot::CapacityAssert::<8, 8>::EXACTLY;
+    }
+
+    #[test]
+    fn generic_over_pots() {
+        fn fill<P: flowerpot::Pot<u32>>(pot: &mut P) -> u32 {
+            let mut next = 0;
+
+            while !pot.is_full() {
+                pot.push(next).unwrap();
+                next += 1;
+            }
+
+            pot.as_slice().iter().sum()
+        }
+
+        let mut small = FlowerPot::<u32, 3>::new();
+        let mut large = FlowerPot::<u32, 10>::new();
+
+        assert!(fill(&mut small) == 3);
+        assert!(fill(&mut large) == 45);
+        assert!(flowerpot::Pot::remaining_capacity(&large) == 0);
+    }
+
+    #[test]
+    fn flatten_nested() {
+        let mut nested = FlowerPot::<FlowerPot<u8, 3>, 2>::new();
+        nested
+            .push(FlowerPot::try_from_iter([1, 2]).unwrap())
+            .unwrap();
+        nested
+            .push(FlowerPot::try_from_iter([3, 4, 5]).unwrap())
+            .unwrap();
+
+        let flat = nested.flatten::<6>();
+
+        assert!(flat[..] == [1, 2, 3, 4, 5], "items should keep their order");
+    }
+
+    #[test]
+    fn group_consecutive() {
+        let pot = FlowerPot::<u8, 8>::try_from_iter([1, 1, 2, 3, 3, 3, 1]).unwrap();
+        let groups = pot.group_by(|a, b| a == b);
+
+        assert!(groups.len() == 4, "should split into runs");
+        assert!(groups[0][..] == [1, 1]);
+        assert!(groups[1][..] == [2]);
+        assert!(groups[2][..] == [3, 3, 3]);
+        assert!(groups[3][..] == [1]);
+
+        let empty = FlowerPot::<u8, 4>::new().group_by(|a, b| a == b);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn partition_by_predicate() {
+        let pot = FlowerPot::<u8, 6>::try_from_iter([1, 2, 3, 4, 5]).unwrap();
+        let (even, odd) = pot.partition(|item| item % 2 == 0);
+
+        assert!(even[..] == [2, 4], "items should keep their order");
+        assert!(odd[..] == [1, 3, 5], "items should keep their order");
+    }
+
+    #[test]
+    fn unzip_pairs() {
+        let pot =
+            FlowerPot::<(u8, char), 4>::try_from_iter([(1, 'a'), (2, 'b'), (3, 'c')]).unwrap();
+        let (numbers, letters) = pot.unzip();
+
+        assert!(numbers[..] == [1, 2, 3]);
+        assert!(letters[..] == ['a', 'b', 'c']);
+    }
+
+    #[test]
+    fn partition_drops_once() {
+        use std::rc::Rc;
+
+        let shared = Rc::new(());
+        let pot = FlowerPot::<Rc<()>, 4>::try_from_iter((0..4).map(|_| shared.clone())).unwrap();
+
+        let mut flip = false;
+        let (left, right) = pot.partition(|_| {
+            flip = !flip;
+            flip
+        });
+
+        assert!(Rc::strong_count(&shared) == 5);
+        drop((left, right));
+        assert!(
+            Rc::strong_count(&shared) == 1,
+            "every item should be dropped exactly once"
+        );
+    }
+
+    #[test]
+    fn collect_results() {
+        use flowerpot::CollectError;
+
+        let pot = FlowerPot::<u8, 3>::collect_ok([Ok::<u8, char>(1), Ok(2)]).unwrap();
+        assert!(pot[..] == [1, 2]);
+
+        let failed = FlowerPot::<u8, 3>::collect_ok([Ok(1), Err('a'), Err('b')]);
+        assert!(
+            failed.err() == Some(CollectError::Item('a')),
+            "should stop at the first error"
+        );
+
+        let full = FlowerPot::<u8, 1>::collect_ok([Ok::<u8, char>(1), Ok(2)]);
+        assert!(
+            full.err() == Some(CollectError::StorageFull),
+            "overflow should be its own error"
+        );
+    }
+
+    #[test]
+    fn collect_results_either() {
+        let items = [Ok(1), Err('a'), Ok(2), Err('b')];
+
+        let (oks, errs) = FlowerPot::<u8, 2>::collect_either::<_, _, 2>(items).unwrap();
+        assert!(oks[..] == [1, 2]);
+        assert!(errs[..] == ['a', 'b']);
+
+        assert!(FlowerPot::<u8, 2>::collect_either::<_, _, 1>(items).is_err());
+    }
+
+    #[test]
+    fn arithmetic_reductions() {
+        let pot = FlowerPot::<u8, 4>::try_from_iter([200, 50, 10]).unwrap();
+
+        assert!(pot.sum_checked().is_none(), "sum should overflow");
+        assert!(pot.sum_saturating() == u8::MAX, "sum should saturate");
+        assert!(pot.min() == Some(&10));
+        assert!(pot.max() == Some(&200));
+
+        let signed = FlowerPot::<i8, 4>::try_from_iter([-100, -100, 50]).unwrap();
+        assert!(
+            signed.sum_saturating() == i8::MIN + 50,
+            "sum should saturate past the lower bound"
+        );
+
+        let empty = FlowerPot::<u8, 4>::new();
+        assert!(empty.sum_checked() == Some(0));
+        assert!(empty.min().is_none());
+    }
+
+    #[test]
+    fn fallible_clones() {
+        use flowerpot::{CollectError, TryClone};
+
+        #[derive(Debug)]
+        struct Handle(u8);
+
+        impl TryClone for Handle {
+            type Error = u8;
+
+            fn try_clone(&self) -> Result<Handle, u8> {
+                if self.0 == 0 {
+                    Err(0)
+                } else {
+                    Ok(Handle(self.0))
+                }
+            }
+        }
+
+        let pot = FlowerPot::<Handle, 3>::try_clone_from_slice(&[Handle(1), Handle(2)]).unwrap();
+        let cloned = pot.try_clone().unwrap();
+        assert!(cloned.len() == 2 && cloned[1].0 == 2);
+
+        let failed = FlowerPot::<Handle, 3>::try_clone_from_slice(&[Handle(1), Handle(0)]);
+        assert!(
+            matches!(failed, Err(CollectError::Item(0))),
+            "should return the clone error"
+        );
+
+        let full = FlowerPot::<Handle, 1>::try_clone_from_slice(&[Handle(1), Handle(2)]);
+        assert!(matches!(full, Err(CollectError::StorageFull)));
+    }
+
+    #[test]
+    fn apply_in_place() {
+        let mut pot = FlowerPot::<u8, 4>::try_from_iter([1, 2, 3]).unwrap();
+
+        pot.apply(|item| *item *= 2);
+        assert!(pot[..] == [2, 4, 6]);
+
+        pot.apply_indexed(|index, item| *item += index as u8);
+        assert!(pot[..] == [2, 5, 8]);
+
+        let result = pot.try_apply(|item| {
+            if *item > 6 {
+                return Err(*item);
+            }
+
+            *item = 0;
+            Ok(())
+        });
+
+        assert!(result == Err(8), "should stop at the first error");
+        assert!(
+            pot[..] == [0, 0, 8],
+            "items before the error should stay modified"
+        );
+    }
+
+    #[test]
+    fn recycle_items() {
+        use std::rc::Rc;
+
+        let shared = Rc::new(());
+        let mut pot = FlowerPot::<(u8, Rc<()>), 4>::new();
+
+        for index in 0..4 {
+            pot.push((index, shared.clone())).unwrap();
+        }
+
+        let mut recycle = pot.recycle();
+
+        while let Some((index, handle)) = recycle.next() {
+            if index == 1 {
+                drop(handle);
+                continue;
+            }
+
+            assert!(recycle.put_back((index * 10, handle)).is_none());
+
+            if index == 2 {
+                break;
+            }
+        }
+
+        assert!(
+            recycle.put_back((5, shared.clone())).is_none(),
+            "should fill the discarded slot"
+        );
+        assert!(
+            recycle.put_back((6, shared.clone())).is_some(),
+            "every slot was filled"
+        );
+        drop(recycle);
+
+        let indexes: Vec<u8> = pot[..].iter().map(|(index, _)| *index).collect();
+        assert!(indexes == [0, 20, 5, 3], "items not yielded should be kept");
+        assert!(
+            Rc::strong_count(&shared) == 5,
+            "only the discarded items should be dropped"
+        );
+    }
+
+    #[test]
+    fn scoped_push() {
+        let mut pot = FlowerPot::<&str, 4>::new();
+        pot.push("global").unwrap();
+
+        {
+            let mut outer = pot.push_scoped("outer").unwrap();
+            assert!(outer.item() == Some(&"outer"));
+
+            {
+                let inner = outer.push_scoped("inner").unwrap();
+                assert!(inner[..] == ["global", "outer", "inner"]);
+            }
+
+            assert!(
+                outer[..] == ["global", "outer"],
+                "inner scope should be popped"
+            );
+            outer.push("leaked").unwrap();
+        }
+
+        assert!(
+            pot[..] == ["global"],
+            "everything pushed in the scope should be popped"
+        );
+
+        let mut guard = pot.push_scoped("returned").unwrap();
+        guard.push("after").unwrap();
+        assert!(guard.pop() == Some("returned"));
+        assert!(pot[..] == ["global"]);
+    }
+
+    #[test]
+    fn operand_stack() {
+        let mut stack = FlowerPot::<i32, 4>::new();
+
+        stack.push_array([1, 2, 3]).unwrap();
+        assert!(
+            stack.push_array([4, 5]).is_err(),
+            "should not push past capacity"
+        );
+        assert!(stack.len() == 3, "failed push should push nothing");
+
+        assert!(stack.peek_n(2) == Some(&[2, 3][..]));
+        assert!(stack.peek_n(4).is_none());
+
+        let [a, b] = stack.pop_array().unwrap();
+        assert!((a, b) == (2, 3), "items should keep their order");
+        assert!(
+            stack.pop_array::<2>().is_none(),
+            "should not pop more than there are"
+        );
+
+        stack.push(a + b).unwrap();
+        assert!(stack.pop_array::<2>() == Some([1, 5]));
+        assert!(stack.pop_array::<0>() == Some([]));
+    }
+
+    #[test]
+    fn pod_bytes_round_trip() {
+        let samples = FlowerPot::<u16, 4>::try_from_iter([1, 0x0203, u16::MAX]).unwrap();
+        let bytes = samples.as_bytes();
+
+        assert!(bytes.len() == 6);
+
+        let mut unaligned = vec![0];
+        unaligned.extend_from_slice(bytes);
+
+        let restored = FlowerPot::<u16, 4>::from_bytes(&unaligned[1..]).unwrap();
+        assert!(restored[..] == samples[..], "should read unaligned bytes");
+
+        assert!(
+            FlowerPot::<u16, 4>::from_bytes(&unaligned[..3]).is_err(),
+            "length should be validated"
+        );
+        assert!(
+            FlowerPot::<u16, 2>::from_bytes(&unaligned[1..]).is_err(),
+            "capacity should be validated"
+        );
+
+        let frames = FlowerPot::<[u8; 2], 2>::from_bytes(b"abcd").unwrap();
+        assert!(frames[..] == [*b"ab", *b"cd"]);
+    }
+
+    #[test]
+    fn fill_pod_in_place() {
+        let mut readings = FlowerPot::<u32, 4>::new();
+
+        readings.resize_zeroed(2).unwrap();
+        assert!(readings[..] == [0, 0]);
+
+        readings
+            .as_bytes_mut()
+            .copy_from_slice(&[1, 0, 0, 0, 2, 0, 0, 0]);
+        assert!(
+            readings[..]
+                == [
+                    u32::from_le_bytes([1, 0, 0, 0]),
+                    u32::from_le_bytes([2, 0, 0, 0])
+                ]
+        );
+
+        assert!(readings.resize_zeroed(5).is_err());
+        readings.resize_zeroed(1).unwrap();
+        assert!(readings.len() == 2, "should never shrink");
+    }
+
+    #[test]
+    fn capacity_from_byte_budget() {
+        type Samples = flowerpot::pot_for_bytes!(u32, 1024);
+        type Odd = flowerpot::pot_for_bytes!([u8; 3], 10);
+
+        let samples = Samples::new();
+        let odd = Odd::new();
+
+        assert!(samples.capacity() == 256);
+        assert!(odd.capacity() == 3, "budget should round down");
+        assert!(flowerpot::capacity_for_bytes::<u64>(20) == 2);
+    }
+
+    #[test]
+    fn dyn_pots() {
+        use flowerpot::{DynPot, Pot};
+
+        fn fill(pot: &mut DynPot<u8>) {
+            let mut next = 0;
+
+            while pot.push(next).is_ok() {
+                next += 1;
+            }
+        }
+
+        let mut small = FlowerPot::<u8, 2>::new();
+        let mut large = FlowerPot::<u8, 5>::new();
+        let pots: [&mut dyn Pot<u8>; 2] = [&mut small, &mut large];
+
+        for pot in pots {
+            fill(pot);
+            assert!(pot.is_full());
+            assert!(pot.pop() == Some(pot.capacity() as u8 - 1));
+        }
+
+        assert!(small[..] == [0] && large[..] == [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn generic_pots() {
+        use flowerpot::{FlowerPotWithOverflow, Pot, StaticPot};
+
+        fn push_evens<P: Pot<u8>>(mut pot: P) -> usize {
+            for item in (0..10).step_by(2) {
+                if pot.push(item).is_err() {
+                    break;
+                }
+            }
+
+            pot.as_slice().iter().map(|&item| item as usize).sum()
+        }
+
+        static STORAGE: StaticPot<u8, 3> = StaticPot::new();
+
+        let pot = STORAGE.take().unwrap();
+        assert!(
+            push_evens(&mut *pot) == 6,
+            "pushes should stop once the pot is full"
+        );
+        assert!(pot[..] == [0, 2, 4]);
+
+        let mut spilled = 0;
+        let overflow = FlowerPotWithOverflow::<u8, 3, _>::new(|_, _| spilled += 1);
+        assert!(push_evens(overflow) == 6);
+        assert!(spilled == 2, "overflowing items should reach the callback");
+    }
+
+    #[test]
+    fn flat_iteration() {
+        use flowerpot::FlatPots;
+
+        let segments = [
+            FlowerPot::<u8, 3>::try_from_iter([1, 2]).unwrap(),
+            FlowerPot::new(),
+            FlowerPot::try_from_iter([3, 4, 5]).unwrap(),
+        ];
+
+        assert!(segments.total_len() == 5);
+        assert!(
+            segments.iter_flat().copied().eq(1..=5),
+            "segments should be yielded in order"
+        );
+
+        let nested = FlowerPot::<FlowerPot<u8, 3>, 4>::try_from_iter(segments).unwrap();
+        assert!(nested.total_len() == 5);
+        assert!(nested.iter_flat().copied().eq(1..=5));
+    }
+
+    #[test]
+    fn fixed_size_windows() {
+        let pot = FlowerPot::<i32, 8>::try_from_iter([1, 2, 3, 4]).unwrap();
+
+        let sums: Vec<i32> = pot.windows_pot::<3>().map(|[a, b, c]| a + b + c).collect();
+        assert!(sums == [6, 9]);
+
+        assert!(pot.windows_pot::<4>().len() == 1);
+        assert!(
+            pot.windows_pot::<5>().next().is_none(),
+            "should yield nothing when too short"
+        );
+        assert!(pot.windows_pot::<2>().next_back() == Some(&[3, 4]));
+    }
+
+    #[test]
+    fn runs_of_equal_keys() {
+        let events = FlowerPot::<(u8, char), 8>::try_from_iter([
+            (1, 'a'),
+            (1, 'b'),
+            (2, 'c'),
+            (3, 'd'),
+            (3, 'e'),
+        ])
+        .unwrap();
+
+        let runs: Vec<&[(u8, char)]> = events.runs_by(|a, b| a.0 == b.0).collect();
+
+        assert!(runs.len() == 3);
+        assert!(runs[0] == [(1, 'a'), (1, 'b')]);
+        assert!(runs[2] == [(3, 'd'), (3, 'e')]);
+        assert!(
+            FlowerPot::<u8, 2>::new()
+                .runs_by(|a, b| a == b)
+                .next()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn select_and_median() {
+        let mut samples = FlowerPot::<u16, 8>::try_from_iter([9, 1, 7, 3, 5]).unwrap();
+
+        let (lower, third, upper) = samples.select_nth_unstable(2).unwrap();
+        assert!(*third == 5);
+        assert!(lower.iter().all(|&item| item <= 5) && upper.iter().all(|&item| item >= 5));
+
+        assert!(samples.select_nth_unstable(5).is_none());
+        assert!(samples.median() == Some(5));
+
+        samples.push(11).unwrap();
+        assert!(
+            samples.median() == Some(5),
+            "should pick the lower middle item"
+        );
+        assert!(FlowerPot::<u16, 4>::new().median().is_none());
+    }
+
+    #[test]
+    fn merge_sorted_pots() {
+        let mut left =
+            FlowerPot::<(u8, char), 8>::try_from_iter([(1, 'l'), (3, 'l'), (5, 'l')]).unwrap();
+        let right = FlowerPot::try_from_iter([(0, 'r'), (3, 'r'), (6, 'r'), (7, 'r')]).unwrap();
+
+        left.merge_sorted(right).unwrap();
+
+        let keys: Vec<u8> = left[..].iter().map(|item| item.0).collect();
+        assert!(keys == [0, 1, 3, 3, 5, 6, 7]);
+        assert!(left[2] == (3, 'l'), "merge should be stable");
+
+        let overflow = FlowerPot::try_from_iter([(9, 'x'), (9, 'y')]).unwrap();
+        let err = left.merge_sorted(overflow).unwrap_err();
+        assert!(
+            err.into_inner().len() == 2,
+            "rejected pot should be handed back"
+        );
+        assert!(left.len() == 7, "failed merge should merge nothing");
+    }
+
+    #[test]
+    fn unique_pushes() {
+        let mut set = FlowerPot::<u8, 3>::new();
+
+        assert!(set.push_unique(2) == Ok(true));
+        assert!(
+            set.push_unique(2) == Ok(false),
+            "duplicate should not be pushed"
+        );
+        assert!(set.push_unique(1) == Ok(true));
+        assert!(set.push_unique(3) == Ok(true));
+        assert!(
+            set.push_unique(1) == Ok(false),
+            "duplicate of a full pot is not an error"
+        );
+        assert!(set.push_unique(4).map_err(|err| err.into_inner()) == Err(4));
+
+        let mut sorted = FlowerPot::<u8, 3>::new();
+
+        for item in [5, 1, 5, 3] {
+            let _ = sorted.insert_sorted_unique(item);
+        }
+
+        assert!(sorted[..] == [1, 3, 5]);
+        assert!(sorted.insert_sorted_unique(3) == Ok(false));
+        assert!(sorted.insert_sorted_unique(0).is_err());
+    }
+
+    #[test]
+    fn key_lookups() {
+        #[derive(Debug, PartialEq)]
+        struct Sensor {
+            id: u16,
+            value: i32,
+        }
+
+        let mut sensors = FlowerPot::<Sensor, 4>::new();
+
+        for (id, value) in [(7, 10), (3, -2), (9, 5)] {
+            let _ = sensors.push(Sensor { id, value });
+        }
+
+        assert!(
+            sensors
+                .find_by_key(3, |sensor| sensor.id)
+                .map(|sensor| sensor.value)
+                == Some(-2)
+        );
+        assert!(sensors.find_by_key(4, |sensor| sensor.id).is_none());
+        assert!(sensors.position_by_key(9, |sensor| sensor.id) == Some(2));
+
+        let removed = sensors.remove_by_key(7, |sensor| sensor.id);
+
+        assert!(removed == Some(Sensor { id: 7, value: 10 }));
+        assert!(
+            sensors.position_by_key(9, |sensor| sensor.id) == Some(1),
+            "order should be kept"
+        );
+        assert!(sensors.remove_by_key(7, |sensor| sensor.id).is_none());
+    }
+
+    #[cfg(not(feature = "forbid-unsafe-api"))]
+    #[test]
+    fn reserving_slots() {
+        let mut pot = FlowerPot::<[u32; 64], 2>::new();
+
+        {
+            let mut slot = pot.reserve().unwrap();
+            let item = slot.as_uninit_mut();
+
+            // SAFETY: every element is written before `assume_init`.
+            unsafe {
+                let ptr = item.as_mut_ptr().cast::<u32>();
+
+                for i in 0..64 {
+                    ptr.add(i).write(i as u32);
+                }
+
+                slot.assume_init();
+            }
+        }
+
+        {
+            let mut slot = pot.reserve().unwrap();
+            slot.write([1; 64])[0] = 7;
+            assert!(slot.is_init());
+        }
+
+        assert!(pot.len() == 2);
+        assert!(pot[0][63] == 63 && pot[1][0] == 7 && pot[1][1] == 1);
+        assert!(pot.reserve().is_err());
+
+        pot.pop();
+        drop(pot.reserve().unwrap());
+        assert!(pot.len() == 1, "unwritten slots should not be pushed");
+    }
+
+    #[cfg(not(feature = "forbid-unsafe-api"))]
+    #[test]
+    fn pushing_in_place() {
+        let mut pot = FlowerPot::<[u8; 2048], 2>::new();
+
+        // SAFETY: the closures fill the whole slot before returning `Ok`.
+        unsafe {
+            pot.push_with(|slot| {
+                slot.as_mut_ptr().cast::<u8>().write_bytes(3, 2048);
+            })
+            .unwrap();
+
+            let failed = pot.try_push_with(|_| Err("sensor offline"));
+            assert!(matches!(
+                failed,
+                Err(flowerpot::CollectError::Item("sensor offline"))
+            ));
+            assert!(pot.len() == 1, "failed initialization should push nothing");
+
+            pot.try_push_with::<(), _>(|slot| {
+                slot.write([9; 2048]);
+                Ok(())
+            })
+            .unwrap();
+
+            assert!(pot.push_with(|_| unreachable!()).is_err());
+        }
+
+        assert!(pot[0][2047] == 3 && pot[1][0] == 9);
+    }
+
+    #[test]
+    fn popping_in_place() {
+        use std::mem::MaybeUninit;
+
+        let mut pot = FlowerPot::<[u16; 512], 3>::new();
+        let _ = pot.push_array([[1; 512], [2; 512], [3; 512]]);
+
+        let mut dst = MaybeUninit::uninit();
+
+        assert!(pot.pop_into(&mut dst));
+        // SAFETY: `pop_into` returned `true`, so `dst` was written.
+        assert!(unsafe { dst.assume_init_ref() }[511] == 3);
+
+        assert!(pot.read_index_into(0, &mut dst));
+        // SAFETY: `read_index_into` returned `true`, so `dst` was written.
+        assert!(unsafe { dst.assume_init_ref() }[0] == 1);
+        assert!(pot.len() == 1 && pot[0][0] == 2, "items should be shifted");
+
+        assert!(!pot.read_index_into(1, &mut dst));
+        pot.clear();
+        assert!(!pot.pop_into(&mut dst));
+    }
+
+    #[test]
+    fn optional_pots() {
+        use flowerpot::OptionPot;
+        use std::mem::size_of;
+
+        assert!(size_of::<OptionPot<u8, 16>>() == size_of::<FlowerPot<u8, 16>>());
+
+        let mut column: Vec<OptionPot<String, 2>> = (0..3).map(|_| OptionPot::none()).collect();
+
+        column[1]
+            .get_or_insert_with(FlowerPot::new)
+            .push("a".to_string())
+            .unwrap();
+
+        assert!(column[0].is_none() && column[1].is_some());
+        assert!(column[1].as_ref().map(|pot| pot.len()) == Some(1));
+
+        let old = column[2].replace(FlowerPot::try_from_iter(["b".to_string()]).unwrap());
+        assert!(old.is_none());
+
+        let taken = column[1].take().unwrap();
+        assert!(taken[0] == "a" && column[1].is_none());
+        assert!(
+            Option::from(OptionPot::some(taken)).is_some_and(|pot: FlowerPot<_, 2>| pot.len() == 1)
+        );
+    }
+
+    #[test]
+    fn static_pots() {
+        #[cfg(target_os = "linux")]
+        flowerpot::static_pot_in_section!(".bss.flowerpot", RX: FlowerPot<u8, 16>);
+        #[cfg(not(target_os = "linux"))]
+        static RX: flowerpot::StaticPot<u8, 16> = flowerpot::StaticPot::new();
+
+        let rx = RX.take().unwrap();
+        rx.put_slice(b"dma").unwrap();
+
+        assert!(RX.is_taken());
+        assert!(RX.take().is_none(), "pot should only be handed out once");
+        assert!(rx[..] == *b"dma");
+    }
+
+    #[test]
+    fn pushing_zeroed() {
+        let mut pot = FlowerPot::<[u32; 256], 2>::new();
+
+        pot.push_zeroed_with(|item| item[255] = 7).unwrap();
+
+        {
+            let mut slot = pot.reserve().unwrap();
+            slot.write_zeroed()[0] = 1;
+        }
+
+        assert!(pot[0][0] == 0 && pot[0][255] == 7);
+        assert!(pot[1][0] == 1 && pot[1][255] == 0);
+        assert!(pot.push_zeroed_with(|_| unreachable!()).is_err());
+
+        pot.pop();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let _ = pot.push_zeroed_with(|_| panic!("sensor offline"));
+        }));
+
+        assert!(result.is_err());
+        assert!(pot.len() == 1, "panicking fills should push nothing");
+    }
+
+    #[test]
+    fn counting_items() {
+        let mut pot = FlowerPot::<u8, 3>::default();
+        assert!(pot.is_empty(), "default should be empty");
+
+        pot.push(1).unwrap();
+        assert!(pot.len() == 1, "A single item should be counted");
+
+        pot.push(2).unwrap();
+        pot.push(3).unwrap();
+        assert!(pot.len() == 3, "every item should be counted");
+    }
+
+    #[test]
+    fn getting_at_the_length() {
+        let mut pot = FlowerPot::<u8, 4>::new();
+        pot.push(1).unwrap();
+        pot.push(2).unwrap();
+
+        assert!(pot.get(1) == Some(&2));
+        assert!(pot.get(2).is_none(), "`get` at the length should fail");
+        assert!(
+            pot.get_mut(2).is_none(),
+            "`get_mut` at the length should fail"
+        );
     }
 }
@@ -0,0 +1,65 @@
+use flowerpot::{Entry, FlowerMap};
+
+#[test]
+fn inserting_and_removing() {
+    let mut map = FlowerMap::<&str, i32, 2>::new();
+
+    assert!(map.insert("a", 1).unwrap().is_none());
+    assert!(map.insert("b", 2).unwrap().is_none());
+    assert!(
+        map.insert("a", 3).unwrap() == Some(1),
+        "old value should be returned"
+    );
+    assert!(
+        map.insert("c", 4).is_err(),
+        "new keys past the capacity should fail"
+    );
+
+    assert!(map.get("a") == Some(&3));
+    assert!(map.remove("a") == Some(3));
+    assert!(!map.contains_key("a"));
+    assert!(map.len() == 1);
+}
+
+#[test]
+fn entries() {
+    let mut counts = FlowerMap::<char, u32, 4>::new();
+
+    for c in "abacab".chars() {
+        *counts.entry(c).or_insert(0).unwrap() += 1;
+    }
+
+    assert!(counts.get(&'a') == Some(&3));
+    assert!(counts.get(&'b') == Some(&2));
+    assert!(counts.get(&'c') == Some(&1));
+
+    counts
+        .entry('c')
+        .and_modify(|count| *count *= 10)
+        .or_default()
+        .unwrap();
+    counts
+        .entry('d')
+        .and_modify(|count| *count *= 10)
+        .or_default()
+        .unwrap();
+    assert!(counts.get(&'c') == Some(&10));
+    assert!(counts.get(&'d') == Some(&0));
+
+    let mut called = false;
+    let result = counts.entry('e').or_insert_with(|| {
+        called = true;
+        1
+    });
+    assert!(
+        result.is_err() && !called,
+        "full maps should not call `default`"
+    );
+
+    match counts.entry('a') {
+        Entry::Occupied(entry) => assert!(entry.remove() == 3),
+        Entry::Vacant(_) => panic!("`a` should be occupied"),
+    }
+
+    assert!(counts.len() == 3);
+}
@@ -0,0 +1,53 @@
+use flowerpot::FlowerMinMaxHeap;
+
+#[test]
+fn popping_both_ends() {
+    let mut heap = FlowerMinMaxHeap::<i32, 8>::new();
+
+    for num in [5, 1, 8, 3, 9, 2, 7, 4] {
+        heap.push(num).unwrap();
+    }
+
+    assert!(heap.push(6).is_err(), "`push` at full capacity should fail");
+    assert!(heap.peek_min() == Some(&1) && heap.peek_max() == Some(&9));
+
+    assert!(heap.pop_max() == Some(9));
+    assert!(heap.pop_min() == Some(1));
+    assert!(heap.pop_max() == Some(8));
+    assert!(heap.pop_min() == Some(2));
+
+    assert!(heap.into_sorted().get_init_slice() == [3, 4, 5, 7]);
+}
+
+#[test]
+fn random_operations() {
+    let mut heap = FlowerMinMaxHeap::<u32, 64>::new();
+    let mut model = Vec::new();
+    let mut state = 0x2545_F491_u32;
+
+    for _ in 0..2000 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+
+        match state % 3 {
+            0 => {
+                model.sort();
+                assert!(heap.pop_min() == (!model.is_empty()).then(|| model.remove(0)));
+            }
+            1 => {
+                model.sort();
+                assert!(heap.pop_max() == model.pop());
+            }
+            _ => {
+                let num = state % 100;
+
+                if heap.push(num).is_ok() {
+                    model.push(num);
+                }
+            }
+        }
+
+        assert!(heap.len() == model.len());
+    }
+}
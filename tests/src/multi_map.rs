@@ -0,0 +1,34 @@
+use flowerpot::FlowerMultiMap;
+
+#[test]
+fn grouped_values() {
+    let mut routes = FlowerMultiMap::<&str, u8, 2, 3>::new();
+
+    routes.insert("temp", 1).unwrap();
+    routes.insert("temp", 2).unwrap();
+    routes.insert("humidity", 3).unwrap();
+
+    assert!(
+        routes.insert("pressure", 4).is_err(),
+        "too many keys should fail"
+    );
+    routes.insert("temp", 5).unwrap();
+    assert!(
+        routes.insert("temp", 6).is_err(),
+        "too many values should fail"
+    );
+
+    assert!(routes.get_all("temp").copied().eq([1, 2, 5]));
+    assert!(routes.get_all("pressure").next().is_none());
+
+    assert!(routes.remove("humidity", &3));
+    assert!(
+        !routes.contains_key("humidity"),
+        "empty groups should be removed"
+    );
+    assert!(!routes.remove("temp", &9));
+
+    let group = routes.remove_all("temp").unwrap();
+    assert!(group.get_init_slice() == [1, 2, 5]);
+    assert!(routes.is_empty());
+}
@@ -0,0 +1,32 @@
+use flowerpot::{FlowerPot, FlowerPotWithOverflow};
+
+#[test]
+fn spilling_when_full() {
+    let mut spilled = Vec::new();
+
+    {
+        let mut pot =
+            FlowerPotWithOverflow::<u32, 3, _>::new(|pot: &mut FlowerPot<u32, 3>, item| {
+                spilled.extend_from_slice(pot.get_init_slice());
+                pot.clear();
+                pot.push(item).unwrap();
+            });
+
+        pot.extend(1..=7);
+        assert!(pot.get_init_slice() == [7]);
+    }
+
+    assert!(spilled == [1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn dropping_with_metrics() {
+    let mut dropped = 0;
+
+    let mut pot =
+        FlowerPotWithOverflow::<u32, 2, _>::new(|_: &mut FlowerPot<u32, 2>, _| dropped += 1);
+    pot.extend(0..5);
+
+    assert!(pot.into_inner().get_init_slice() == [0, 1]);
+    assert!(dropped == 3);
+}
@@ -0,0 +1,89 @@
+use flowerpot::FlowerPipe;
+
+#[test]
+fn grant_commit_read_release() {
+    let mut pipe = FlowerPipe::<8>::new();
+    let (mut tx, mut rx) = pipe.split();
+
+    assert!(
+        rx.read().is_err(),
+        "an empty pipe should have nothing to read"
+    );
+
+    let mut grant = tx.grant(4).unwrap();
+    grant.copy_from_slice(&[1, 2, 3, 4]);
+    grant.commit(3);
+
+    let grant = rx.read().unwrap();
+    assert!(*grant == [1, 2, 3], "invalid contents of read grant");
+    grant.release(3);
+
+    assert!(rx.read().is_err());
+}
+
+#[test]
+fn wrapping_around() {
+    let mut pipe = FlowerPipe::<8>::new();
+    let (mut tx, mut rx) = pipe.split();
+
+    tx.grant(6).unwrap().commit(6);
+    rx.read().unwrap().release(4);
+
+    assert!(
+        tx.grant(3).is_ok(),
+        "grant should wrap around to the start of the pipe"
+    );
+
+    let mut grant = tx.grant(3).unwrap();
+    grant.copy_from_slice(&[7, 8, 9]);
+    grant.commit(3);
+
+    assert!(
+        tx.grant(1).is_err(),
+        "grant should not overtake the read position"
+    );
+
+    let grant = rx.read().unwrap();
+    assert!(grant.len() == 2, "tail of the pipe should be read first");
+    grant.release(2);
+
+    let grant = rx.read().unwrap();
+    assert!(*grant == [7, 8, 9], "invalid contents after wrapping");
+}
+
+#[test]
+fn threaded_transfer() {
+    let mut pipe = FlowerPipe::<16>::new();
+    let (mut tx, mut rx) = pipe.split();
+
+    std::thread::scope(|scope| {
+        scope.spawn(move || {
+            let mut next = 0_u8;
+
+            while next < 200 {
+                if let Ok(mut grant) = tx.grant(3) {
+                    for byte in grant.iter_mut() {
+                        *byte = next;
+                        next = next.wrapping_add(1);
+                    }
+
+                    grant.commit(3);
+                }
+            }
+        });
+
+        let mut expected = 0_u8;
+
+        while expected < 200 {
+            if let Ok(grant) = rx.read() {
+                for byte in grant.iter() {
+                    assert!(*byte == expected, "bytes arrived out of order");
+                    expected = expected.wrapping_add(1);
+                }
+
+                let len = grant.len();
+                grant.release(len);
+            }
+        }
+    });
+}
@@ -0,0 +1,64 @@
+use flowerpot::{FlowerPot, PotPool};
+use std::rc::Rc;
+
+#[test]
+fn chains_share_a_pool() {
+    let pool = PotPool::<u32, 4, 3>::new();
+    let mut first = pool.chain();
+    let mut second = pool.chain();
+
+    for item in 0..8 {
+        first.push(item).unwrap();
+    }
+
+    assert!(first.segment_count() == 2 && pool.available() == 1);
+    assert!(first.iter().copied().eq(0..8));
+
+    for item in 8..12 {
+        second.push(item).unwrap();
+    }
+
+    assert!(
+        second.push(12).is_err(),
+        "push should fail once the pool is exhausted"
+    );
+    assert!(second.len() == 4);
+
+    assert!(first.pop() == Some(7));
+    assert!(first.pop_segment().unwrap()[..] == [0, 1, 2, 3]);
+    assert!(first.len() == 3 && pool.available() == 1);
+
+    drop(first);
+    assert!(
+        pool.available() == 2,
+        "dropped chains should release their segments"
+    );
+}
+
+#[test]
+fn appending_and_clearing() {
+    let pool = PotPool::<Rc<()>, 2, 2>::new();
+    let item = Rc::new(());
+    let mut chain = pool.chain();
+
+    chain.push(item.clone()).unwrap();
+    chain
+        .append_pot(FlowerPot::try_from_iter([item.clone(), item.clone()]).unwrap())
+        .unwrap();
+    chain.append_pot(FlowerPot::new()).unwrap();
+
+    assert!(chain.len() == 3 && chain.segment_count() == 2);
+    assert!(chain.segments().map(<[_]>::len).eq([1, 2]));
+    assert!(
+        chain
+            .append_pot(FlowerPot::try_from_iter([item.clone()]).unwrap())
+            .is_err()
+    );
+
+    chain.clear();
+    assert!(chain.is_empty() && pool.available() == 2);
+    assert!(
+        Rc::strong_count(&item) == 1,
+        "cleared items should be dropped"
+    );
+}
@@ -0,0 +1,14 @@
+use flowerpot::prelude::*;
+
+#[test]
+fn aliases_and_traits_in_scope() {
+    let mut small = SmallPot8::<u8>::new();
+    let line = Line::<char>::new();
+    let page = Page::new();
+
+    assert!(small.capacity() == 8 && line.capacity() == 64 && page.capacity() == 4096);
+
+    let report = write_pot!(small, "{}", 1234);
+    assert!(report.written() == 4);
+    assert!(Pot::as_slice(&small) == b"1234");
+}
@@ -0,0 +1,36 @@
+use flowerpot::RateLimiter;
+
+#[test]
+fn limiting_bursts() {
+    let mut limiter = RateLimiter::<3>::new(10);
+
+    assert!(limiter.try_acquire(0));
+    assert!(limiter.try_acquire(2));
+    assert!(limiter.try_acquire(2));
+    assert!(
+        !limiter.try_acquire(5),
+        "burst should be limited to 3 events"
+    );
+    assert!(limiter.available(5) == 0);
+    assert!(limiter.wait_time(5) == 5);
+
+    assert!(limiter.available(10) == 1, "oldest token should come back");
+    assert!(limiter.wait_time(10) == 0);
+    assert!(limiter.try_acquire(10));
+    assert!(!limiter.try_acquire(11));
+    assert!(limiter.available(12) == 2);
+
+    limiter.reset();
+    assert!(limiter.available(12) == 3);
+}
+
+#[test]
+fn huge_windows() {
+    let mut limiter = RateLimiter::<1>::new(u64::MAX);
+
+    assert!(limiter.try_acquire(10));
+    assert!(
+        limiter.wait_time(20) == u64::MAX - 20,
+        "waiting should saturate instead of overflowing"
+    );
+}
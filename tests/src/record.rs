@@ -0,0 +1,48 @@
+use flowerpot::{FlowerPot, RecordWriter};
+
+#[test]
+fn writing_records() {
+    let mut pot = FlowerPot::<u8, 64>::new();
+
+    {
+        let mut writer = RecordWriter::new(&mut pot);
+
+        writer.field(b"time").unwrap();
+        writer.field(b"note").unwrap();
+        writer.end_record().unwrap();
+
+        writer.field_display(&1200).unwrap();
+        writer.field(b"said \"hi\", left").unwrap();
+        assert!(writer.fields() == 2);
+        writer.end_record().unwrap();
+
+        writer.field_display(&-3.5).unwrap();
+    }
+
+    assert!(
+        pot[..] == *b"time,note\n1200,\"said \"\"hi\"\", left\"\n",
+        "unfinished records should be removed on drop"
+    );
+}
+
+#[test]
+fn full_records() {
+    let mut pot = FlowerPot::<u8, 10>::new();
+    let mut writer = RecordWriter::with_delimiter(&mut pot, b';');
+
+    writer.field(b"ab").unwrap();
+    writer.end_record().unwrap();
+    writer.field(b"c;d").unwrap();
+    assert!(writer.field(b"e\"").is_err(), "quoted field should not fit");
+    assert!(writer.fields() == 1);
+
+    assert!(writer.end_record().is_ok());
+    assert!(writer.complete_lines() == b"ab\n\"c;d\"\n");
+
+    writer.clear_complete();
+    writer.field(b"12345").unwrap();
+    assert!(writer.field(b"x\"").is_err());
+    writer.field(b"xy").unwrap();
+    writer.end_record().unwrap();
+    assert!(writer.complete_lines() == b"12345;xy\n");
+}
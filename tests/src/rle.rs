@@ -0,0 +1,30 @@
+use flowerpot::FlowerRle;
+
+#[test]
+fn compressing_runs() {
+    let mut samples = FlowerRle::<u8, 3>::new();
+
+    for sample in [0, 0, 0, 5, 5, 0, 0] {
+        samples.push(sample).unwrap();
+    }
+
+    assert!(samples.runs() == [(0, 3), (5, 2), (0, 2)]);
+    assert!(samples.len() == 7);
+    assert!(
+        samples.push(0).is_ok(),
+        "equal items should extend the last run"
+    );
+    assert!(
+        samples.push(1).is_err(),
+        "new runs past the capacity should fail"
+    );
+
+    assert!(samples.iter().copied().eq([0, 0, 0, 5, 5, 0, 0, 0]));
+
+    assert!(samples.pop() == Some(0));
+    assert!(samples.pop() == Some(0));
+    assert!(samples.pop() == Some(0));
+    assert!(samples.run_count() == 2, "empty runs should be removed");
+    assert!(samples.pop() == Some(5));
+    assert!(samples.len() == 4);
+}
@@ -0,0 +1,58 @@
+use flowerpot::SeqLockPot;
+use std::thread;
+
+#[test]
+fn snapshots_are_never_torn() {
+    let mut lock = SeqLockPot::<u64, 8>::new();
+    let (mut writer, reader) = lock.split();
+
+    thread::scope(|scope| {
+        for _ in 0..2 {
+            let reader = reader.clone();
+
+            scope.spawn(move || {
+                for _ in 0..10_000 {
+                    let snapshot = reader.read();
+                    let items = &snapshot[..];
+
+                    assert!(
+                        items
+                            .iter()
+                            .all(|&item| item == items.len() as u64 * 1000 + items[0] % 1000),
+                        "snapshot should never mix two writes"
+                    );
+                }
+            });
+        }
+
+        for round in 0..10_000_u64 {
+            writer.write(|pot| {
+                pot.clear();
+
+                let len = round as usize % 8 + 1;
+                pot.push_iter((0..len).map(|_| len as u64 * 1000 + round % 1000));
+            });
+        }
+    });
+
+    assert!(writer.get_ref().len() == 9_999 % 8 + 1);
+}
+
+#[test]
+fn panicking_writes_unlock() {
+    let mut lock = SeqLockPot::<u32, 4>::new();
+    let (mut writer, reader) = lock.split();
+
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        writer.write(|pot| {
+            pot.push(1).unwrap();
+            panic!("writer failed");
+        })
+    }));
+
+    assert!(result.is_err());
+    assert!(
+        reader.try_read().is_some(),
+        "readers should not be locked out after a panicking write"
+    );
+}
@@ -0,0 +1,46 @@
+use flowerpot::ShardedPots;
+use std::thread;
+
+#[test]
+fn collect_from_threads() {
+    let pots = ShardedPots::<u32, 16, 4>::new();
+
+    thread::scope(|scope| {
+        for shard in 0..4 {
+            let pots = &pots;
+
+            scope.spawn(move || {
+                for index in 0..10 {
+                    pots.push_to(shard, shard as u32 * 100 + index).unwrap();
+                }
+            });
+        }
+    });
+
+    assert!(pots.len() == 40);
+    assert!(pots.push_to(4, 0).is_err(), "shard should not exist");
+
+    let all = pots.drain_all::<64>();
+
+    assert!(all.len() == 40);
+    assert!(
+        all[..10] == *(0..10).collect::<Vec<_>>(),
+        "items should be drained shard by shard"
+    );
+    assert!(pots.is_empty(), "draining should empty every shard");
+}
+
+#[test]
+fn push_to_own_shard() {
+    let pots = ShardedPots::<u8, 4, 2>::new();
+
+    for item in 0..4 {
+        pots.push(item).unwrap();
+    }
+
+    assert!(
+        pots.push(4).is_err(),
+        "shard of the current thread should be full"
+    );
+    assert!(pots.drain_all::<8>()[..] == [0, 1, 2, 3]);
+}
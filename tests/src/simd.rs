@@ -0,0 +1,50 @@
+use flowerpot::FlowerPot;
+
+#[test]
+fn lane_wise_ops() {
+    let mut samples = FlowerPot::<f32, 256>::new();
+    samples.push_iter((0..100).map(|index| index as f32));
+
+    assert!(samples.contains(99.0));
+    assert!(!samples.contains(100.0));
+    assert!(samples.position(42.0) == Some(42));
+    assert!(samples.sum() == 4950.0);
+
+    let expected: Vec<f32> = (0..100).map(|index| index as f32).collect();
+    assert!(samples.eq_slice(&expected), "should equal the pushed items");
+
+    let mut other = expected.clone();
+    other[97] = -1.0;
+    assert!(!samples.eq_slice(&other), "should notice a differing item");
+
+    samples.fill(0.5);
+    assert!(samples.is_full());
+    assert!(samples.sum() == 5028.0, "fill should keep the pushed items");
+}
+
+#[test]
+fn small_pots_and_wrapping() {
+    let pot = FlowerPot::<u8, 4>::try_from_iter([200, 100, 3]).unwrap();
+
+    assert!(pot.contains(3));
+    assert!(pot.sum() == 47, "integer sums should wrap");
+
+    let mut big = FlowerPot::<u16, 64>::new();
+    big.fill(7);
+    assert!(big.position(7) == Some(0));
+    assert!(big.position(8).is_none());
+}
+
+#[test]
+#[cfg(feature = "metrics")]
+fn filling_rejects_nothing() {
+    let mut pot = FlowerPot::<u8, 8>::new();
+    pot.push(1).unwrap();
+    pot.fill(2);
+
+    assert!(pot[..] == [1, 2, 2, 2, 2, 2, 2, 2]);
+    assert!(
+        pot.rejected_pushes() == 0,
+        "fill should not count as a rejected push"
+    );
+}
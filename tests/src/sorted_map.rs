@@ -0,0 +1,46 @@
+use flowerpot::FlowerSortedMap;
+use std::ops::Bound;
+
+#[test]
+fn sorted_inserts() {
+    let mut map = FlowerSortedMap::<u32, &str, 4>::new();
+
+    map.insert(30, "c").unwrap();
+    map.insert(10, "a").unwrap();
+    map.insert(20, "b").unwrap();
+    assert!(map.insert(20, "B").unwrap() == Some("b"));
+    map.insert(40, "d").unwrap();
+    assert!(
+        map.insert(50, "e").is_err(),
+        "new keys past the capacity should fail"
+    );
+
+    assert!(
+        map.keys().copied().eq([10, 20, 30, 40]),
+        "keys should stay sorted"
+    );
+    assert!(map.get(&20) == Some(&"B"));
+    assert!(map.remove(&10) == Some("a"));
+    assert!(map.first() == Some((&20, &"B")));
+    assert!(map.last() == Some((&40, &"d")));
+}
+
+#[test]
+fn range_queries() {
+    let mut map = FlowerSortedMap::<u32, u32, 16>::new();
+
+    for key in (0..10).rev() {
+        map.insert(key * 10, key).unwrap();
+    }
+
+    assert!(map.range(20..50).map(|(_, v)| *v).eq([2, 3, 4]));
+    assert!(map.range(25..=50).map(|(_, v)| *v).eq([3, 4, 5]));
+    assert!(map.range(..15).map(|(_, v)| *v).eq([0, 1]));
+    assert!(map.range(85..).map(|(_, v)| *v).eq([9]));
+    assert!(
+        map.range((Bound::Excluded(40), Bound::Excluded(40)))
+            .next()
+            .is_none(),
+        "empty ranges should yield nothing"
+    );
+}
@@ -0,0 +1,61 @@
+use flowerpot::SparseFlowerPot;
+use std::rc::Rc;
+
+#[test]
+fn slot_addressing() {
+    let mut table = SparseFlowerPot::<&str, 8>::new();
+
+    assert!(table.set(5, "imu").unwrap().is_none());
+    assert!(table.set(2, "gps").unwrap().is_none());
+    assert!(table.set(5, "baro").unwrap() == Some("imu"));
+    assert!(
+        table.set(8, "lidar").is_err(),
+        "index should be out of bounds"
+    );
+
+    assert!(table.len() == 2 && table.contains(2) && !table.contains(3));
+    assert!(table.get(5) == Some(&"baro"));
+    assert!(table.iter().eq([(2, &"gps"), (5, &"baro")]));
+
+    assert!(table.insert("radio").unwrap() == 0);
+    assert!(table.first_free() == Some(1));
+
+    for (_, name) in table.iter_mut() {
+        *name = "off";
+    }
+
+    assert!(table.take(2) == Some("off"));
+    assert!(table.take(2).is_none());
+    assert!(table.len() == 2);
+}
+
+#[test]
+fn wide_bitmaps() {
+    let mut table = SparseFlowerPot::<u32, 130, 3>::new();
+
+    for index in 0..130 {
+        table.set(index, index as u32).unwrap();
+    }
+
+    assert!(table.insert(0).is_err(), "every index should be occupied");
+    assert!(table.take(129) == Some(129));
+    assert!(table.first_free() == Some(129));
+    assert!(table.iter().map(|(index, _)| index).eq(0..129));
+}
+
+#[test]
+fn dropping_occupied() {
+    let item = Rc::new(());
+
+    {
+        let mut table = SparseFlowerPot::<Rc<()>, 4>::new();
+        table.set(1, item.clone()).unwrap();
+        table.set(3, item.clone()).unwrap();
+        assert!(Rc::strong_count(&item) == 3);
+    }
+
+    assert!(
+        Rc::strong_count(&item) == 1,
+        "occupied items should be dropped"
+    );
+}
@@ -0,0 +1,115 @@
+use flowerpot::FlowerWorkDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn worker_and_stealer_ends() {
+    let mut deque = FlowerWorkDeque::<i32, 4>::new();
+    let (mut worker, stealer) = deque.split();
+
+    for num in 1..5 {
+        worker.push(num).unwrap();
+    }
+
+    assert!(
+        worker.push(5).is_err(),
+        "`push` at full capacity should fail"
+    );
+
+    assert!(worker.pop() == Some(4), "worker should pop the newest item");
+    assert!(
+        stealer.steal() == Some(1),
+        "stealer should take the oldest item"
+    );
+    assert!(worker.pop() == Some(3));
+    assert!(stealer.steal() == Some(2));
+
+    assert!(worker.pop().is_none());
+    assert!(stealer.steal().is_none());
+    assert!(worker.is_empty());
+}
+
+#[test]
+fn concurrent_stealing() {
+    const TASKS: usize = 1000;
+
+    let mut deque = FlowerWorkDeque::<usize, 16>::new();
+    let (mut worker, stealer) = deque.split();
+    let sum = AtomicUsize::new(0);
+
+    std::thread::scope(|scope| {
+        for _ in 0..3 {
+            let stealer = stealer.clone();
+            let sum = &sum;
+
+            scope.spawn(move || {
+                let mut idle = 0;
+
+                while idle < 10_000 {
+                    match stealer.steal() {
+                        Some(task) => {
+                            sum.fetch_add(task, Ordering::Relaxed);
+                            idle = 0;
+                        }
+                        None => idle += 1,
+                    }
+                }
+            });
+        }
+
+        for task in 1..=TASKS {
+            while worker.push(task).is_err() {
+                if let Some(task) = worker.pop() {
+                    sum.fetch_add(task, Ordering::Relaxed);
+                }
+            }
+        }
+
+        while let Some(task) = worker.pop() {
+            sum.fetch_add(task, Ordering::Relaxed);
+        }
+    });
+
+    assert!(
+        sum.load(Ordering::Relaxed) == TASKS * (TASKS + 1) / 2,
+        "every task should be taken exactly once"
+    );
+}
+
+#[test]
+fn dropping_leftovers() {
+    let counter = std::rc::Rc::new(());
+
+    {
+        let mut deque = FlowerWorkDeque::<std::rc::Rc<()>, 4>::new();
+        let (mut worker, _) = deque.split();
+
+        worker.push(counter.clone()).unwrap();
+        worker.push(counter.clone()).unwrap();
+    }
+
+    assert!(
+        std::rc::Rc::strong_count(&counter) == 1,
+        "leftover items should be dropped"
+    );
+}
+
+#[test]
+fn wraps_around_power_of_two_capacity() {
+    FlowerWorkDeque::<u32, 8>::assert_capacity_power_of_two();
+
+    let mut deque = FlowerWorkDeque::<u32, 8>::new();
+    let (mut worker, stealer) = deque.split();
+
+    for round in 0..5 {
+        for num in 0..8 {
+            worker.push(round * 8 + num).unwrap();
+        }
+
+        for num in 0..8 {
+            assert!(
+                stealer.steal() == Some(round * 8 + num),
+                "indexes should wrap around"
+            );
+        }
+    }
+}
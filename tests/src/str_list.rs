@@ -0,0 +1,32 @@
+use flowerpot::FlowerStrList;
+
+#[test]
+fn packing_strings() {
+    let mut list = FlowerStrList::<16, 4>::new();
+
+    list.push_str("eth0").unwrap();
+    list.push_str("").unwrap();
+    list.push_str("wlän0").unwrap();
+
+    assert!(list.get(0) == Some("eth0"));
+    assert!(list.get(1) == Some(""));
+    assert!(list.get(2) == Some("wlän0"));
+    assert!(list.get(3).is_none());
+    assert!(list.remaining_bytes() == 6);
+
+    assert!(
+        list.push_str("loopback").is_err(),
+        "arena overflow should fail"
+    );
+    assert!(list.len() == 3, "a failed push should not append anything");
+
+    list.push_str("lo").unwrap();
+    assert!(list.push_str("x").is_err(), "index overflow should fail");
+
+    assert!(list.pop());
+    assert!(list.iter().eq(["eth0", "", "wlän0"]));
+    assert!(
+        list.remaining_bytes() == 6,
+        "popping should free arena bytes"
+    );
+}
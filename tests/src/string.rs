@@ -0,0 +1,121 @@
+use flowerpot::{FlowerPot, FlowerString, write_pot};
+use std::fmt::Write;
+use std::io::ErrorKind;
+
+#[test]
+fn pushing_and_popping() {
+    let mut string = FlowerString::<8>::new();
+
+    string.push_str("flöw").unwrap();
+    string.push('r').unwrap();
+    assert!(string == "flöwr");
+    assert!(string.len() == 6);
+
+    assert!(
+        string.push_str("pot").is_err(),
+        "too long strings should fail"
+    );
+    assert!(
+        string == "flöwr",
+        "a failed push should not append anything"
+    );
+
+    assert!(string.pop() == Some('r'));
+    assert!(string.pop() == Some('w'));
+    assert!(string.pop() == Some('ö'));
+    assert!(&*string == "fl");
+
+    write!(string, "{}", 42).unwrap();
+    assert!(format!("{string}") == "fl42");
+}
+
+#[test]
+fn parsing() {
+    let string: FlowerString<4> = "pot".parse().unwrap();
+    assert!(string == "pot");
+    assert!("flowerpot".parse::<FlowerString<4>>().is_err());
+
+    let pot = FlowerPot::<u16, 4>::parse_split("1,20,300", ",").unwrap();
+    assert!(pot.get_init_slice() == [1, 20, 300]);
+
+    let err = FlowerPot::<u16, 2>::parse_split("1,20,300", ",").unwrap_err();
+    assert!(err.kind() == ErrorKind::StorageFull);
+
+    let err = FlowerPot::<u16, 4>::parse_split("1,x", ",").unwrap_err();
+    assert!(err.kind() == ErrorKind::InvalidData);
+}
+
+#[test]
+fn write_pot_reports_truncation() {
+    let mut pot = FlowerPot::<u8, 8>::new();
+
+    let report = write_pot!(pot, "{}-{}", 12, 34);
+    assert!(report.written() == 5 && !report.is_truncated());
+
+    let report = write_pot!(&mut pot, "{:>5}", "ab");
+    assert!(
+        report.written() == 3 && report.is_truncated(),
+        "output should be cut off"
+    );
+    assert!(pot[..] == *b"12-34   ");
+
+    let mut string = FlowerString::<4>::new();
+    let report = write_pot!(string, "ñññ");
+    assert!(report.written() == 4 && report.is_truncated());
+    assert!(string == "ññ", "characters should never be split");
+}
+
+#[test]
+fn joining() {
+    let pot = FlowerPot::<i32, 4>::try_from_iter([1, -2, 3]).unwrap();
+
+    let csv = pot.to_delimited::<16>(",").unwrap();
+    assert!(csv == "1,-2,3");
+
+    let mut line = FlowerString::<10>::new();
+    let _ = line.push_str("v=");
+    pot.join_into(&mut line, ", ").unwrap();
+    assert!(line == "v=1, -2, 3");
+
+    assert!(pot.join_into(&mut line, ";").is_err());
+    assert!(line == "v=1, -2, 3", "failed joins should append nothing");
+    assert!(pot.to_delimited::<5>(",").is_err());
+    assert!(FlowerPot::<u8, 2>::new().to_delimited::<0>(",").unwrap() == "");
+}
+
+#[test]
+fn accumulating_utf8() {
+    let mut acc = flowerpot::Utf8Accumulator::<8>::new();
+    let bytes = "añ€".as_bytes();
+
+    assert!(acc.feed(&bytes[..2]) == 2);
+    assert!(acc.next_str().unwrap() == Some("a"));
+    assert!(
+        acc.next_str().unwrap().is_none(),
+        "split character should wait"
+    );
+    assert!(acc.buffered() == [0xC3]);
+
+    acc.feed(&bytes[2..5]);
+    assert!(acc.next_str().unwrap() == Some("ñ"));
+    acc.feed(&bytes[5..]);
+    assert!(acc.next_str().unwrap() == Some("€"));
+
+    acc.feed(&[b'x', 0xFF, b'y']);
+    assert!(acc.next_str().unwrap() == Some("x"));
+    assert!(acc.next_str().is_err());
+    assert!(
+        acc.next_str().unwrap() == Some("y"),
+        "invalid bytes should be skipped"
+    );
+    assert!(acc.buffered().is_empty());
+}
+
+#[test]
+fn bytes_and_pots() {
+    let string: FlowerString<8> = "pot".parse().unwrap();
+
+    let bytes: &[u8] = string.as_bytes();
+    assert!(bytes == b"pot", "`as_bytes` should be the one of `str`");
+    assert!(string.as_pot().remaining_capacity() == 5);
+}
@@ -0,0 +1,27 @@
+use flowerpot::TopK;
+
+#[test]
+fn keeping_largest() {
+    let mut top = TopK::<u32, 3>::largest();
+
+    assert!(top.offer(5).is_none());
+    top.extend([1, 9, 7]);
+    assert!(top.threshold() == Some(&5));
+
+    assert!(top.offer(2) == Some(2), "worse items should be rejected");
+    assert!(
+        top.offer(8) == Some(5),
+        "the worst item should be displaced"
+    );
+
+    assert!(top.into_sorted().get_init_slice() == [9, 8, 7]);
+}
+
+#[test]
+fn keeping_smallest() {
+    let mut top = TopK::<i32, 2>::smallest();
+
+    top.extend((0..100).map(|num| (num * 37) % 101 - 50));
+
+    assert!(top.into_sorted().get_init_slice() == [-50, -49]);
+}
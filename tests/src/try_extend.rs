@@ -0,0 +1,47 @@
+use flowerpot::{FlowerMap, FlowerPot, FlowerRle, FlowerString, TopK, TryCollectInto, TryExtend};
+
+/// Generic code that works with any bounded container.
+fn fill<C: TryExtend<u8>>(dst: &mut C) -> Option<Vec<u8>> {
+    match (1..=5).try_collect_into(dst) {
+        Ok(()) => None,
+        Err(err) => {
+            let (item, rest) = err.into_inner();
+
+            Some(std::iter::once(item).chain(rest).collect())
+        }
+    }
+}
+
+#[test]
+fn extending_any_container() {
+    let mut pot = FlowerPot::<u8, 3>::new();
+    assert!(
+        fill(&mut pot) == Some(vec![4, 5]),
+        "leftovers should be handed back"
+    );
+    assert!(pot[..] == [1, 2, 3]);
+
+    let mut rle = FlowerRle::<u8, 8>::new();
+    assert!(fill(&mut rle).is_none());
+
+    let mut top = TopK::<u8, 2>::largest();
+    assert!(fill(&mut top).is_none());
+    assert!(top.into_sorted()[..] == [5, 4]);
+
+    let mut string = FlowerString::<3>::new();
+    let rest = string.try_extend("añb".chars());
+    assert!(matches!(rest, Err(ref err) if err.value().0 == 'b'));
+    assert!(string == "añ");
+}
+
+#[test]
+fn extending_maps() {
+    let mut map = FlowerMap::<&str, u8, 2>::new();
+
+    map.try_extend([("a", 1), ("b", 2), ("a", 3)]).unwrap();
+    assert!(map.get("a") == Some(&3), "existing keys should still fit");
+
+    let err = map.try_push(("c", 4)).unwrap_err();
+    assert!(err.into_inner() == ("c", 4));
+    assert!(map.len() == 2);
+}
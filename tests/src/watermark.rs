@@ -0,0 +1,31 @@
+use flowerpot::{FlowerPot, WatermarkEvent, Watermarks};
+
+#[test]
+fn percent_thresholds() {
+    let mut pot = FlowerPot::<u8, 10>::new();
+    pot.push_iter(0..5);
+
+    assert!(pot.is_above(40));
+    assert!(!pot.is_above(50), "exactly half should not be above half");
+}
+
+#[test]
+fn hysteresis() {
+    let mut pot = FlowerPot::<u8, 10>::new();
+    let mut watermarks = Watermarks::from_percent::<10>(80, 20).unwrap();
+    let mut events = Vec::new();
+
+    for _ in 0..10 {
+        pot.push(0).unwrap();
+        events.extend(pot.check_watermarks(&mut watermarks));
+    }
+
+    assert!(watermarks.is_throttled());
+
+    while pot.pop().is_some() {
+        events.extend(pot.check_watermarks(&mut watermarks));
+    }
+
+    assert!(events == [WatermarkEvent::High, WatermarkEvent::Low]);
+    assert!(Watermarks::new(2, 5).is_err(), "low above high should fail");
+}
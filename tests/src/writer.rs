@@ -0,0 +1,73 @@
+use flowerpot::{ChunkedWriter, FlowerPot};
+use std::io::Write;
+
+#[test]
+fn flushing_full_chunks() {
+    let mut pot = FlowerPot::<u8, 4>::new();
+    let mut chunks = Vec::new();
+
+    {
+        let mut writer = ChunkedWriter::new(&mut pot, |chunk: &[u8]| {
+            chunks.push(chunk.to_vec());
+            Ok(())
+        });
+
+        writer.write_all(b"flowerpot").unwrap();
+        assert!(writer.buffer() == b"t", "remainder should stay buffered");
+    }
+
+    assert!(
+        chunks == [b"flow".to_vec(), b"erpo".to_vec(), b"t".to_vec()],
+        "invalid chunks passed to the flush callback"
+    );
+    assert!(pot.is_empty(), "buffer should be reused after flushing");
+}
+
+#[test]
+fn formatting_into_chunks() {
+    let mut pot = FlowerPot::<u8, 8>::new();
+    let mut out = Vec::new();
+
+    {
+        let mut writer = ChunkedWriter::new(&mut pot, |chunk: &[u8]| {
+            out.extend_from_slice(chunk);
+            Ok(())
+        });
+
+        std::fmt::Write::write_fmt(&mut writer, format_args!("{}-{}", 1234, "abcdef")).unwrap();
+        writer.flush().unwrap();
+    }
+
+    assert!(out == b"1234-abcdef");
+}
+
+#[test]
+fn retrying_failed_flushes() {
+    let mut pot = FlowerPot::<u8, 4>::new();
+    let mut out = Vec::new();
+    let mut fail = true;
+
+    {
+        let mut writer = ChunkedWriter::new(&mut pot, |chunk: &[u8]| {
+            if std::mem::take(&mut fail) {
+                return Err(std::io::Error::from(std::io::ErrorKind::Other));
+            }
+
+            out.extend_from_slice(chunk);
+            Ok(())
+        });
+
+        assert!(writer.write(b"abcdef").unwrap() == 4);
+        assert!(
+            writer.write(b"ef").is_err(),
+            "flush error should be returned"
+        );
+        assert!(writer.write(b"ef").unwrap() == 2);
+        writer.flush().unwrap();
+    }
+
+    assert!(
+        out == b"abcdef",
+        "accepted bytes should not be written twice"
+    );
+}